@@ -249,10 +249,8 @@ bountyRouter.post('/link-x', async (req: Request, res: Response) => {
   try {
     const body: LinkXRequest = req.body;
     
-    // TODO:
-    // 1. Verify wallet signature of "Link X:@<handle> to AgentGrind"
-    // 2. Call X API to verify handle exists
-    // 3. If valid, build + return link_x on-chain transaction
+    // TODO: proxy to the app's POST /api/x/link, which takes the handle from
+    // the X OAuth session and returns link_x partially signed by config.x_verifier
     
     res.json({
       success: true,
@@ -6,7 +6,7 @@ import { PublicKey, SystemProgram } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
 
 import idl from '../../../idl/agentgrind.json';
-import { agentProfilePda, bountyPda, creatorProfilePda, decodeBounty, type Bounty, vaultPda } from '../../../lib/agentgrind';
+import { agentProfilePda, bountyPda, creatorProfilePda, decodeBounty, legacyBountyPda, type Bounty, vaultPda } from '../../../lib/agentgrind';
 import { useDemoAgentMode } from '../../../lib/demo-mode';
 import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 
@@ -48,7 +48,10 @@ export default function BountyDetails(props: any) {
       try {
         if (!creatorPk) throw new Error('Invalid creator pubkey');
 
-        const [bountyAddr] = bountyPda(creatorPk, params.bountyId);
+        // numeric → per-creator index; otherwise a legacy raw-id bounty
+        const [bountyAddr] = /^\d+$/.test(params.bountyId)
+          ? bountyPda(creatorPk, BigInt(params.bountyId))
+          : legacyBountyPda(creatorPk, params.bountyId);
         const info = await connection.getAccountInfo(bountyAddr);
         if (!info?.data) throw new Error('Bounty account not found');
 
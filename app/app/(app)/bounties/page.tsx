@@ -3,7 +3,15 @@
 import { useEffect, useMemo, useState } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { useDemoAgentMode } from '../lib/demo-mode';
-import { AGENTGRIND_PROGRAM_ID, agentProfilePda, BOUNTY_ACCOUNT_SIZE, decodeBounty, type Bounty } from '../lib/agentgrind';
+import {
+  AGENTGRIND_PROGRAM_ID,
+  agentProfilePda,
+  BOUNTY_ACCOUNT_SIZE,
+  BOUNTY_V1_SIZE,
+  decodeBounty,
+  fetchBountyAccounts,
+  type Bounty,
+} from '../lib/agentgrind';
 import { PublicKey, SystemProgram } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
 import idl from '../idl/agentgrind.json';
@@ -16,6 +24,7 @@ const statusBadge = (status: string) => {
     Completed: 'badge badge-completed',
     Cancelled: 'badge badge-cancelled',
     Rejected: 'badge badge-rejected',
+    Disputed: 'badge badge-rejected',
   };
   return map[status] || 'badge';
 };
@@ -139,9 +148,7 @@ export default function BountiesPage() {
       setLoading(true);
       setError('');
       try {
-        const accounts = await connection.getProgramAccounts(programId, {
-          filters: [{ dataSize: BOUNTY_ACCOUNT_SIZE }],
-        });
+        const accounts = await fetchBountyAccounts(connection, programId);
 
         const decoded = accounts
           .map((a) => {
@@ -336,7 +343,8 @@ export default function BountiesPage() {
       )}
 
       <div className="mt-6 text-xs text-brand-textMuted">
-        Filter: program accounts where <span className="font-mono">dataSize = {BOUNTY_ACCOUNT_SIZE}</span>.
+        Filter: program accounts where <span className="font-mono">dataSize = {BOUNTY_ACCOUNT_SIZE}</span> (or{' '}
+        <span className="font-mono">{BOUNTY_V1_SIZE}</span> for bounties not yet migrated from v1).
       </div>
     </div>
   );
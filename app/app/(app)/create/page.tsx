@@ -5,7 +5,6 @@ import { useConnection, useWallet } from '@solana/wallet-adapter-react';
 import { PublicKey, SystemProgram } from '@solana/web3.js';
 import {
   createAssociatedTokenAccountInstruction,
-  createTransferInstruction,
   getAssociatedTokenAddress,
   getAccount,
   TOKEN_PROGRAM_ID,
@@ -13,7 +12,7 @@ import {
 import * as anchor from '@coral-xyz/anchor';
 
 import idl from '../idl/agentgrind.json';
-import { AGENTGRIND_PROGRAM_ID, bountyPda, vaultPda } from '../lib/agentgrind';
+import { AGENTGRIND_PROGRAM_ID, bountyPda, configPda, decodeNextBountyIndex, vaultPda } from '../lib/agentgrind';
 
 const USDC_MINT_DEVNET = new PublicKey('Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr');
 
@@ -28,10 +27,11 @@ export default function CreateBounty() {
   const [submitting, setSubmitting] = useState(false);
   const [error, setError] = useState('');
   const [sig, setSig] = useState('');
+  const [bountyIndex, setBountyIndex] = useState<bigint | null>(null);
   const [usdcBalance, setUsdcBalance] = useState<number | null>(null);
   const [balanceLoading, setBalanceLoading] = useState(false);
 
-  // internal id (PDA seed); user shouldn't choose
+  // on-chain label + metadata key (the PDA comes from the profile's next_bounty_index)
   const bountyId = useMemo(() => `b_${Date.now().toString(36)}_${Math.random().toString(36).slice(2, 8)}`,
     []
   );
@@ -105,18 +105,26 @@ export default function CreateBounty() {
         throw new Error(`Insufficient USDC balance. You have ${usdcBalance.toFixed(2)} USDC, need ${Number(amount).toFixed(2)} USDC.`);
       }
 
-      const feeAtoms = Math.floor(grossAtoms * 0.1);
-      const netAtoms = grossAtoms - feeAtoms;
+      // fee is split off on-chain (config.fee_bps); keep a client-side sanity check
+      const netAtoms = grossAtoms - Math.floor(grossAtoms * 0.1);
       if (netAtoms <= 0) throw new Error('Amount too small after fee');
 
       const deadline = Math.floor(Date.now() / 1000) + Number(deadlineDays) * 86400;
 
-      const [bounty] = bountyPda(creator, bountyId);
-      const [vault] = vaultPda(bounty);
+      // on-chain commitment to the description the claimer signs up for
+      const descriptionHash = Array.from(
+        new Uint8Array(await crypto.subtle.digest('SHA-256', new TextEncoder().encode(description.trim())))
+      );
+
       const [profile] = PublicKey.findProgramAddressSync(
         [Buffer.from('profile'), creator.toBuffer()],
         AGENTGRIND_PROGRAM_ID
       );
+      const profileInfo = await connection.getAccountInfo(profile);
+      const index = profileInfo ? decodeNextBountyIndex(profileInfo.data) : BigInt(0);
+      const [bounty] = bountyPda(creator, index);
+      const [vault] = vaultPda(bounty);
+      const [config] = configPda();
 
       const creatorTokenAccount = await getAssociatedTokenAddress(mint, creator);
       
@@ -144,27 +152,19 @@ export default function CreateBounty() {
         );
       }
 
-      // fee transfer
-      if (feeAtoms > 0) {
-        ix.push(
-          createTransferInstruction(
-            creatorTokenAccount,
-            treasuryTokenAccount,
-            creator,
-            feeAtoms
-          )
-        );
-      }
-
-      // program instruction (net atoms into vault)
+      // program instruction (gross atoms; program sends the fee to treasury)
       const programIx = await program.methods
-        .createBounty(bountyId, new anchor.BN(netAtoms), new anchor.BN(deadline))
+        .createBounty(bountyId, new anchor.BN(grossAtoms), new anchor.BN(deadline), [], { standard: {} }, descriptionHash, null)
         .accounts({
           bounty,
           vault,
           profile,
+          config,
           mint,
           creatorTokenAccount,
+          treasuryTokenAccount,
+          acceptedMint: null, // cluster USDC needs no allowlist entry
+          treasury: null, // only for native-SOL bounties
           creator,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -183,6 +183,8 @@ export default function CreateBounty() {
       const txSig = await connection.sendRawTransaction(signed.serialize(), { skipPreflight: false });
       await connection.confirmTransaction({ signature: txSig, blockhash, lastValidBlockHeight }, 'confirmed');
 
+      setBountyIndex(index);
+
       // store off-chain metadata
       const metaRes = await fetch('/api/metadata', {
         method: 'POST',
@@ -331,7 +333,7 @@ export default function CreateBounty() {
             </div>
 
             <div className="flex gap-3">
-              <a href={`/bounties/${wallet.publicKey?.toBase58()}/${bountyId}`} className="btn-primary text-sm flex-1 text-center">
+              <a href={`/bounties/${wallet.publicKey?.toBase58()}/${bountyIndex ?? bountyId}`} className="btn-primary text-sm flex-1 text-center">
                 View bounty
               </a>
               <button
@@ -1,4 +1,5 @@
-import { PublicKey } from '@solana/web3.js';
+import { utils } from '@coral-xyz/anchor';
+import { Connection, PublicKey } from '@solana/web3.js';
 
 // Devnet program id (same as declare_id)
 export const AGENTGRIND_PROGRAM_ID = new PublicKey(
@@ -19,13 +20,47 @@ export function creatorProfilePda(wallet: PublicKey) {
   );
 }
 
-export function bountyPda(creator: PublicKey, bountyId: string) {
+// Bounties are numbered per creator: third seed is the u64 LE index
+// (creator profile's next_bounty_index at creation time)
+export function bountyPda(creator: PublicKey, index: number | bigint) {
+  const seed = Buffer.alloc(8);
+  seed.writeBigUInt64LE(BigInt(index));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('bounty'), creator.toBuffer(), seed],
+    AGENTGRIND_PROGRAM_ID
+  );
+}
+
+// Index the creator's next bounty will get (0 before their profile exists)
+export function decodeNextBountyIndex(profileData: Buffer) {
+  // disc 8 + version 1 + wallet 32 + reputation 8 + 6 u32 counters, then x_handle, x_verified
+  const handleLen = profileData.readUInt32LE(73);
+  return profileData.readBigUInt64LE(73 + 4 + handleLen + 1);
+}
+
+export function legacyBountyPda(creator: PublicKey, bountyId: string) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('bounty'), creator.toBuffer(), Buffer.from(bountyId)],
     AGENTGRIND_PROGRAM_ID
   );
 }
 
+// X handles are registered case-insensitively: sha256 of the normalized handle
+export function xHandlePda(xHandle: string) {
+  const normalized = xHandle.replace(/^@+/, '').toLowerCase();
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('x_handle'), Buffer.from(utils.sha256.hash(normalized), 'hex')],
+    AGENTGRIND_PROGRAM_ID
+  );
+}
+
+export function configPda() {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    AGENTGRIND_PROGRAM_ID
+  );
+}
+
 export function vaultPda(bounty: PublicKey) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('vault'), bounty.toBuffer()],
@@ -33,7 +68,22 @@ export function vaultPda(bounty: PublicKey) {
   );
 }
 
-export const BOUNTY_ACCOUNT_SIZE = 719;
+// Current layout (v2: version byte after the discriminator). v1 accounts are
+// smaller and unversioned until someone runs migrate_bounty / migrate_creator_profile.
+export const BOUNTY_ACCOUNT_SIZE = 880;
+export const BOUNTY_V1_SIZE = 719;
+export const CREATOR_PROFILE_V1_SIZE = 138;
+
+// Every bounty account: current layout plus v1 accounts nobody has migrated yet
+// (getProgramAccounts takes one dataSize per filter, so one query per layout)
+export async function fetchBountyAccounts(connection: Connection, programId = AGENTGRIND_PROGRAM_ID) {
+  const [current, v1] = await Promise.all(
+    [BOUNTY_ACCOUNT_SIZE, BOUNTY_V1_SIZE].map((dataSize) =>
+      connection.getProgramAccounts(programId, { filters: [{ dataSize }] })
+    )
+  );
+  return [...current, ...v1];
+}
 
 type CreatorProfile = {
   wallet: string;
@@ -43,6 +93,7 @@ type CreatorProfile = {
   total_rejected: number;
   total_auto_finalized: number;
   total_cancelled: number;
+  total_disputes_lost: number;
   x_handle: string;
   x_verified: boolean;
   bump: number;
@@ -54,7 +105,8 @@ export type BountyStatus =
   | 'Submitted'
   | 'Completed'
   | 'Cancelled'
-  | 'Rejected';
+  | 'Rejected'
+  | 'Disputed';
 
 export type Bounty = {
   creator: string;
@@ -67,6 +119,7 @@ export type Bounty = {
   proof_submitted_at: number;
   rejection_reason: string;
   bounty_id: string;
+  claim_expires_at: number;
   bump: number;
 };
 
@@ -119,6 +172,8 @@ function decodeStatus(u: number): BountyStatus {
       return 'Cancelled';
     case 5:
       return 'Rejected';
+    case 6:
+      return 'Disputed';
     default:
       return 'Open';
   }
@@ -127,7 +182,8 @@ function decodeStatus(u: number): BountyStatus {
 export function decodeCreatorProfile(data: Uint8Array): CreatorProfile {
   // Anchor discriminator = first 8 bytes
   const dv = new DataView(data.buffer, data.byteOffset, data.byteLength);
-  let o = 8;
+  const v1 = data.byteLength === CREATOR_PROFILE_V1_SIZE;
+  let o = v1 ? 8 : 9; // disc (+ version)
 
   const wallet = new PublicKey(data.slice(o, o + 32)).toBase58();
   o += 32;
@@ -145,6 +201,9 @@ export function decodeCreatorProfile(data: Uint8Array): CreatorProfile {
   o += 4;
   const total_cancelled = readU32LE(dv, o);
   o += 4;
+  // v1 predates total_disputes_lost
+  const total_disputes_lost = v1 ? 0 : readU32LE(dv, o);
+  if (!v1) o += 4;
 
   const x = readString(dv, o);
   const x_handle = x.value;
@@ -153,6 +212,14 @@ export function decodeCreatorProfile(data: Uint8Array): CreatorProfile {
   const x_verified = dv.getUint8(o) === 1;
   o += 1;
 
+  if (!v1) {
+    // next_bounty_index, small_bounty_streak, last_rep_update, x_changed_at
+    o += 8 + 4 + 8 + 8;
+    o = readOptionPubkey(data, o).offset; // pending_transfer
+    o = readOptionPubkey(data, o).offset; // transferred_to
+    o += 4 + 32; // live_bounties, reserved
+  }
+
   const bump = dv.getUint8(o);
 
   return {
@@ -163,6 +230,7 @@ export function decodeCreatorProfile(data: Uint8Array): CreatorProfile {
     total_rejected,
     total_auto_finalized,
     total_cancelled,
+    total_disputes_lost,
     x_handle,
     x_verified,
     bump,
@@ -171,7 +239,7 @@ export function decodeCreatorProfile(data: Uint8Array): CreatorProfile {
 
 export function decodeBounty(data: Uint8Array): Bounty {
   const dv = new DataView(data.buffer, data.byteOffset, data.byteLength);
-  let o = 8; // disc
+  let o = data.byteLength === BOUNTY_V1_SIZE ? 8 : 9; // disc (+ version)
 
   const creator = new PublicKey(data.slice(o, o + 32)).toBase58();
   o += 32;
@@ -207,6 +275,17 @@ export function decodeBounty(data: Uint8Array): Bounty {
   const bounty_id = bid.value;
   o = bid.offset;
 
+  let claim_expires_at = 0;
+  if (data.byteLength !== BOUNTY_V1_SIZE) {
+    claim_expires_at = readI64LE(dv, o);
+    o += 8;
+    o += 8; // rejected_at
+    o += 4 + readU32LE(dv, o) * 8; // milestones
+    // current_milestone, released_amount, kind, currency, entry_count, entries_paid, description_hash,
+    // seed_scheme, index, review_window_secs, reserved
+    o += 1 + 8 + 1 + 1 + 4 + 4 + 32 + 1 + 8 + 8 + 32;
+  }
+
   const bump = dv.getUint8(o);
 
   return {
@@ -220,6 +299,7 @@ export function decodeBounty(data: Uint8Array): Bounty {
     proof_submitted_at,
     rejection_reason,
     bounty_id,
+    claim_expires_at,
     bump,
   };
 }
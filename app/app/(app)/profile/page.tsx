@@ -75,7 +75,7 @@ export default function Profile() {
     ? { label: 'Full Access', color: 'text-brand-green' }
     : reputation >= 30
       ? { label: 'Limited', color: 'text-yellow-400' }
-      : { label: 'Blocked', color: 'text-red-400' };
+      : { label: 'Probation', color: 'text-red-400' };
 
   const confirmLinkX = async () => {
     setLinkError('');
@@ -92,17 +92,19 @@ export default function Profile() {
 
     setLinking(true);
     try {
-      const provider = new anchor.AnchorProvider(connection, wallet as any, { commitment: 'confirmed' });
-      const program = new anchor.Program(idl as any, provider);
-      const handle = xHandleFromOAuth.replace(/^@/, '');
+      // The API checks the handle from the OAuth session and co-signs as config.x_verifier
+      const resp = await fetch('/api/x/link', {
+        method: 'POST',
+        headers: { 'content-type': 'application/json' },
+        body: JSON.stringify({ wallet: publicKey.toBase58() }),
+      });
+      const json: any = await resp.json();
+      if (!resp.ok || !json?.ok) throw new Error(json?.message || json?.error || 'Failed to build link_x transaction');
 
-      const sig = await program.methods
-        .linkX(handle)
-        .accounts({
-          profile: pda,
-          authority: publicKey,
-        })
-        .rpc();
+      const tx = anchor.web3.Transaction.from(Buffer.from(json.transaction, 'base64'));
+      const signed = await wallet.signTransaction(tx);
+      const sig = await connection.sendRawTransaction(signed.serialize());
+      await connection.confirmTransaction(sig, 'confirmed');
 
       setLinkSig(sig);
       // refresh profile
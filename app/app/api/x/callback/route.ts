@@ -74,6 +74,14 @@ export async function GET(req: Request) {
   to.searchParams.set('x_handle', `@${username}`);
 
   const res = NextResponse.redirect(to.toString());
+  // verified handle for /api/x/link, which co-signs link_x (the query param is display-only)
+  res.cookies.set('ag_x_handle', username, {
+    httpOnly: true,
+    secure: true,
+    sameSite: 'lax',
+    path: '/',
+    maxAge: 10 * 60,
+  });
   // clear cookies
   res.cookies.set('ag_x_state', '', { path: '/', maxAge: 0 });
   res.cookies.set('ag_x_verifier', '', { path: '/', maxAge: 0 });
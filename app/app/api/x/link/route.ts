@@ -0,0 +1,96 @@
+import { NextResponse } from 'next/server';
+import { utils } from '@coral-xyz/anchor';
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
+import {
+  AGENTGRIND_PROGRAM_ID,
+  configPda,
+  creatorProfilePda,
+  xHandlePda,
+} from '../../../(app)/lib/agentgrind';
+
+const MAX_X_HANDLE_LEN = 64;
+
+// config.x_verifier keypair (JSON byte array, same format as a Solana keypair file)
+function verifierKeypair() {
+  const raw = process.env.X_VERIFIER_SECRET_KEY;
+  if (!raw) return null;
+  return Keypair.fromSecretKey(Uint8Array.from(JSON.parse(raw)));
+}
+
+// link_x(x_handle): Anchor discriminator + borsh string
+function linkXInstruction(authority: PublicKey, verifier: PublicKey, handle: string) {
+  const discriminator = Buffer.from(utils.sha256.hash('global:link_x'), 'hex').subarray(0, 8);
+  const handleBytes = Buffer.from(handle, 'utf8');
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(handleBytes.length);
+
+  return new TransactionInstruction({
+    programId: AGENTGRIND_PROGRAM_ID,
+    keys: [
+      { pubkey: creatorProfilePda(authority)[0], isSigner: false, isWritable: true },
+      { pubkey: xHandlePda(handle)[0], isSigner: false, isWritable: true },
+      { pubkey: configPda()[0], isSigner: false, isWritable: false },
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: verifier, isSigner: true, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.concat([discriminator, len, handleBytes]),
+  });
+}
+
+// Returns a link_x transaction for `wallet`, already signed by the verifier.
+// The handle comes from the httpOnly cookie set by the OAuth callback, never
+// from the request, so a wallet can only link the X account it just signed in with.
+export async function POST(req: Request) {
+  try {
+    const raw = await req.text();
+    const body = raw ? JSON.parse(raw) : {};
+
+    let wallet: PublicKey;
+    try {
+      wallet = new PublicKey(String(body.wallet || ''));
+    } catch {
+      return NextResponse.json({ ok: false, error: 'invalid_wallet' }, { status: 400 });
+    }
+
+    const cookieHandle = (req.headers.get('cookie') || '').match(/(?:^|; )ag_x_handle=([^;]+)/)?.[1];
+    const handle = cookieHandle ? decodeURIComponent(cookieHandle).replace(/^@+/, '') : '';
+    if (!handle) {
+      return NextResponse.json({ ok: false, error: 'x_not_verified' }, { status: 401 });
+    }
+    if (handle.length > MAX_X_HANDLE_LEN) {
+      return NextResponse.json({ ok: false, error: 'x_handle_too_long' }, { status: 400 });
+    }
+
+    const verifier = verifierKeypair();
+    if (!verifier) {
+      return NextResponse.json({ ok: false, error: 'missing_X_VERIFIER_SECRET_KEY' }, { status: 500 });
+    }
+
+    const rpc = process.env.NEXT_PUBLIC_SOLANA_RPC_ENDPOINT || 'https://api.devnet.solana.com';
+    const connection = new Connection(rpc, 'confirmed');
+    const { blockhash } = await connection.getLatestBlockhash('confirmed');
+
+    const tx = new Transaction({ feePayer: wallet, recentBlockhash: blockhash });
+    tx.add(linkXInstruction(wallet, verifier.publicKey, handle));
+    tx.partialSign(verifier);
+
+    return NextResponse.json({
+      ok: true,
+      x_handle: handle,
+      transaction: tx.serialize({ requireAllSignatures: false }).toString('base64'),
+    });
+  } catch (e: any) {
+    return NextResponse.json(
+      { ok: false, error: 'link_x_failed', message: e?.message || String(e) },
+      { status: 500 }
+    );
+  }
+}
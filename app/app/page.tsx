@@ -2,7 +2,7 @@
 
 import { useEffect, useState } from 'react';
 import { useConnection } from '@solana/wallet-adapter-react';
-import { AGENTGRIND_PROGRAM_ID, BOUNTY_ACCOUNT_SIZE, decodeBounty } from './(app)/lib/agentgrind';
+import { decodeBounty, fetchBountyAccounts } from './(app)/lib/agentgrind';
 
 export default function Landing() {
   const { connection } = useConnection();
@@ -14,9 +14,7 @@ export default function Landing() {
 
     async function fetchStats() {
       try {
-        const accounts = await connection.getProgramAccounts(AGENTGRIND_PROGRAM_ID, {
-          filters: [{ dataSize: BOUNTY_ACCOUNT_SIZE }],
-        });
+        const accounts = await fetchBountyAccounts(connection);
 
         const bounties = accounts
           .map((a) => {
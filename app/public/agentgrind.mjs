@@ -32,7 +32,8 @@ import {
 } from '@solana/web3.js';
 
 const PROGRAM_ID = new PublicKey('HMUV19dpEUPxjSYdqnp4usgcsjHp6WrZ5ijutmKXcTDz');
-const BOUNTY_ACCOUNT_SIZE = 719;
+const BOUNTY_ACCOUNT_SIZE = 880;  // current (v2) layout
+const BOUNTY_V1_SIZE = 719;        // unversioned, until migrate_bounty runs
 
 function usage(code = 1) {
   console.error(`\nAgentGrind CLI\n\nUsage:\n  node agentgrind.mjs list\n  node agentgrind.mjs status <creator> <bounty_id>\n  node agentgrind.mjs claim <creator> <bounty_id>\n  node agentgrind.mjs submit-proof <creator> <bounty_id> <proof_url>\n  node agentgrind.mjs abandon <creator> <bounty_id>\n  node agentgrind.mjs approve <creator> <bounty_id>\n  node agentgrind.mjs reject <creator> <bounty_id> <reason>\n  node agentgrind.mjs finalize <creator> <bounty_id>\n\nEnv:\n  SOLANA_KEYPAIR=...\n  AG_RPC_URL=...\n`);
@@ -60,13 +61,32 @@ function encodeString(s) {
   return Buffer.concat([len, b]);
 }
 
-function bountyPda(creator, bountyId) {
+// Current scheme: bounties are numbered per creator, third seed = u64 LE index
+function indexedBountyPda(creator, index) {
+  const seed = Buffer.alloc(8);
+  seed.writeBigUInt64LE(BigInt(index));
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('bounty'), creator.toBuffer(), seed],
+    PROGRAM_ID
+  )[0];
+}
+
+// Bounties created before per-creator numbering live at the raw-id address
+function legacyBountyPda(creator, bountyId) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('bounty'), creator.toBuffer(), Buffer.from(bountyId)],
     PROGRAM_ID
   )[0];
 }
 
+// Address of an existing bounty: a numeric <bounty_id> is the creator's bounty
+// index; anything else is a legacy raw-id bounty
+async function findBounty(connection, creator, bountyId) {
+  if (/^\d+$/.test(bountyId)) return indexedBountyPda(creator, bountyId);
+  if (Buffer.byteLength(bountyId) > 32) throw new Error(`No bounty with id ${bountyId}`);
+  return legacyBountyPda(creator, bountyId);
+}
+
 function agentProfilePda(agent) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('agent'), agent.toBuffer()],
@@ -81,6 +101,10 @@ function creatorProfilePda(creator) {
   )[0];
 }
 
+function configPda() {
+  return PublicKey.findProgramAddressSync([Buffer.from('config')], PROGRAM_ID)[0];
+}
+
 function vaultPda(bounty) {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('vault'), bounty.toBuffer()],
@@ -91,13 +115,41 @@ function vaultPda(bounty) {
 const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
 const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL');
 
-function associatedTokenAddress(owner, mint) {
+function associatedTokenAddress(owner, mint, tokenProgram = TOKEN_PROGRAM_ID) {
   return PublicKey.findProgramAddressSync(
-    [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    [owner.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
     ASSOCIATED_TOKEN_PROGRAM_ID
   )[0];
 }
 
+// Anchor reads the program id in an Option<...> account slot as None
+function optionalMeta(pubkey, isWritable) {
+  return pubkey
+    ? { pubkey, isSigner: false, isWritable }
+    : { pubkey: PROGRAM_ID, isSigner: false, isWritable: false };
+}
+
+// Escrow accounts a payout needs, in Accounts order: token bounties pass the vault,
+// mint, claimer token account and the mint's token program (SPL or Token-2022);
+// native-SOL bounties (default mint) pass the claimer wallet instead
+async function payoutAccounts(connection, bounty, decoded) {
+  const mint = new PublicKey(decoded.mint);
+  const claimer = new PublicKey(decoded.claimer);
+  if (mint.equals(PublicKey.default)) {
+    return { vault: null, mint: null, claimerTokenAccount: null, claimer, tokenProgram: null };
+  }
+  const mintInfo = await connection.getAccountInfo(mint);
+  if (!mintInfo) throw new Error('mint_not_found');
+  const tokenProgram = mintInfo.owner;
+  return {
+    vault: vaultPda(bounty),
+    mint,
+    claimerTokenAccount: associatedTokenAddress(claimer, mint, tokenProgram),
+    claimer: null,
+    tokenProgram,
+  };
+}
+
 function readU32LE(dv, o) {
   return dv.getUint32(o, true);
 }
@@ -138,13 +190,14 @@ function decodeStatus(u) {
     case 3: return 'Completed';
     case 4: return 'Cancelled';
     case 5: return 'Rejected';
+    case 6: return 'Disputed';
     default: return 'Open';
   }
 }
 
 function decodeBounty(data) {
   const dv = new DataView(data.buffer, data.byteOffset, data.byteLength);
-  let o = 8;
+  let o = data.byteLength === BOUNTY_V1_SIZE ? 8 : 9; // disc (+ version)
   const creator = new PublicKey(data.slice(o, o + 32)).toBase58();
   o += 32;
   const mint = new PublicKey(data.slice(o, o + 32)).toBase58();
@@ -169,8 +222,15 @@ function decodeBounty(data) {
   const bid = readString(dv, o);
   const bounty_id = bid.value;
   o = bid.offset;
+  let claim_expires_at = 0;
+  if (data.byteLength !== BOUNTY_V1_SIZE) {
+    claim_expires_at = readI64LE(dv, o);
+    o += 8 + 8; // claim_expires_at, rejected_at
+    o += 4 + readU32LE(dv, o) * 8; // milestones
+    o += 1 + 8 + 1 + 1 + 4 + 4 + 32 + 1 + 8 + 8 + 32; // current_milestone .. reserved
+  }
   const bump = dv.getUint8(o);
-  return { creator, mint, amount, deadline, status, claimer, proof_uri, proof_submitted_at, rejection_reason, bounty_id, bump };
+  return { creator, mint, amount, deadline, status, claimer, proof_uri, proof_submitted_at, rejection_reason, bounty_id, claim_expires_at, bump };
 }
 
 async function main() {
@@ -181,7 +241,14 @@ async function main() {
   const connection = new Connection(rpc, 'confirmed');
 
   if (cmd === 'list') {
-    const accts = await connection.getProgramAccounts(PROGRAM_ID, { filters: [{ dataSize: BOUNTY_ACCOUNT_SIZE }] });
+    // One query per layout: v1 bounties stay listed until someone migrates them
+    const accts = (
+      await Promise.all(
+        [BOUNTY_ACCOUNT_SIZE, BOUNTY_V1_SIZE].map((dataSize) =>
+          connection.getProgramAccounts(PROGRAM_ID, { filters: [{ dataSize }] })
+        )
+      )
+    ).flat();
     const items = accts.map((a) => ({ address: a.pubkey.toBase58(), ...decodeBounty(a.account.data) }));
     items.sort((x, y) => y.deadline - x.deadline);
     console.log(JSON.stringify({ ok: true, cluster: rpc, programId: PROGRAM_ID.toBase58(), bounties: items }, null, 2));
@@ -192,7 +259,7 @@ async function main() {
     const [creatorStr, bountyId] = rest;
     if (!creatorStr || !bountyId) usage();
     const creator = new PublicKey(creatorStr);
-    const addr = bountyPda(creator, bountyId);
+    const addr = await findBounty(connection, creator, bountyId);
     const info = await connection.getAccountInfo(addr);
     if (!info?.data) throw new Error('bounty_not_found');
     console.log(JSON.stringify({ ok: true, address: addr.toBase58(), ...decodeBounty(info.data) }, null, 2));
@@ -205,7 +272,7 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
     const agentProfile = agentProfilePda(payer.publicKey);
 
     const ix = new TransactionInstruction({
@@ -213,6 +280,7 @@ async function main() {
       keys: [
         { pubkey: bounty, isSigner: false, isWritable: true },
         { pubkey: agentProfile, isSigner: false, isWritable: true },
+        { pubkey: configPda(), isSigner: false, isWritable: false },
         { pubkey: payer.publicKey, isSigner: true, isWritable: true },
         { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       ],
@@ -231,7 +299,7 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
     const agentProfile = agentProfilePda(payer.publicKey);
 
     const data = Buffer.concat([discriminator('submit_proof'), encodeString(proofUrl)]);
@@ -258,7 +326,7 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
     const agentProfile = agentProfilePda(payer.publicKey);
 
     const ix = new TransactionInstruction({
@@ -266,6 +334,7 @@ async function main() {
       keys: [
         { pubkey: bounty, isSigner: false, isWritable: true },
         { pubkey: agentProfile, isSigner: false, isWritable: true },
+        { pubkey: configPda(), isSigner: false, isWritable: false },
         { pubkey: payer.publicKey, isSigner: true, isWritable: false },
       ],
       data: Buffer.concat([discriminator('abandon_claim')]),
@@ -283,28 +352,30 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
 
     const info = await connection.getAccountInfo(bounty);
     if (!info?.data) throw new Error('bounty_not_found');
     const decoded = decodeBounty(info.data);
     if (!decoded.claimer) throw new Error('bounty_missing_claimer');
 
-    const vault = vaultPda(bounty);
     const profile = creatorProfilePda(payer.publicKey);
-    const mint = new PublicKey(decoded.mint);
-    const claimer = new PublicKey(decoded.claimer);
-    const claimerTokenAccount = associatedTokenAddress(claimer, mint);
+    const agentProfile = agentProfilePda(new PublicKey(decoded.claimer));
+    const payout = await payoutAccounts(connection, bounty, decoded);
 
     const ix = new TransactionInstruction({
       programId: PROGRAM_ID,
       keys: [
         { pubkey: bounty, isSigner: false, isWritable: true },
-        { pubkey: vault, isSigner: false, isWritable: true },
+        optionalMeta(payout.vault, true),
+        optionalMeta(payout.mint, true),
         { pubkey: profile, isSigner: false, isWritable: true },
-        { pubkey: claimerTokenAccount, isSigner: false, isWritable: true },
-        { pubkey: payer.publicKey, isSigner: true, isWritable: false },
-        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        { pubkey: agentProfile, isSigner: false, isWritable: true },
+        { pubkey: configPda(), isSigner: false, isWritable: false },
+        optionalMeta(payout.claimerTokenAccount, true),
+        optionalMeta(payout.claimer, true),
+        { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+        optionalMeta(payout.tokenProgram, false),
       ],
       data: Buffer.concat([discriminator('approve_and_pay')]),
     });
@@ -322,9 +393,15 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
     const profile = creatorProfilePda(payer.publicKey);
 
+    const info = await connection.getAccountInfo(bounty);
+    if (!info?.data) throw new Error('bounty_not_found');
+    const decoded = decodeBounty(info.data);
+    if (!decoded.claimer) throw new Error('bounty_missing_claimer');
+    const agentProfile = agentProfilePda(new PublicKey(decoded.claimer));
+
     const data = Buffer.concat([discriminator('reject_bounty'), encodeString(reason)]);
 
     const ix = new TransactionInstruction({
@@ -332,6 +409,8 @@ async function main() {
       keys: [
         { pubkey: bounty, isSigner: false, isWritable: true },
         { pubkey: profile, isSigner: false, isWritable: true },
+        { pubkey: agentProfile, isSigner: false, isWritable: true },
+        { pubkey: configPda(), isSigner: false, isWritable: false },
         { pubkey: payer.publicKey, isSigner: true, isWritable: false },
       ],
       data,
@@ -349,28 +428,30 @@ async function main() {
 
     const payer = getKeypair();
     const creator = new PublicKey(creatorStr);
-    const bounty = bountyPda(creator, bountyId);
+    const bounty = await findBounty(connection, creator, bountyId);
 
     const info = await connection.getAccountInfo(bounty);
     if (!info?.data) throw new Error('bounty_not_found');
     const decoded = decodeBounty(info.data);
     if (!decoded.claimer) throw new Error('bounty_missing_claimer');
 
-    const vault = vaultPda(bounty);
     const creatorProfile = creatorProfilePda(new PublicKey(decoded.creator));
-    const mint = new PublicKey(decoded.mint);
-    const claimer = new PublicKey(decoded.claimer);
-    const claimerTokenAccount = associatedTokenAddress(claimer, mint);
+    const agentProfile = agentProfilePda(new PublicKey(decoded.claimer));
+    const payout = await payoutAccounts(connection, bounty, decoded);
 
     const ix = new TransactionInstruction({
       programId: PROGRAM_ID,
       keys: [
         { pubkey: bounty, isSigner: false, isWritable: true },
-        { pubkey: vault, isSigner: false, isWritable: true },
+        { pubkey: configPda(), isSigner: false, isWritable: false },
+        optionalMeta(payout.vault, true),
+        optionalMeta(payout.mint, true),
         { pubkey: creatorProfile, isSigner: false, isWritable: true },
+        { pubkey: agentProfile, isSigner: false, isWritable: true },
         { pubkey: payer.publicKey, isSigner: true, isWritable: true },
-        { pubkey: claimerTokenAccount, isSigner: false, isWritable: true },
-        { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        optionalMeta(payout.claimerTokenAccount, true),
+        optionalMeta(payout.claimer, true),
+        optionalMeta(payout.tokenProgram, false),
       ],
       data: Buffer.concat([discriminator('finalize_bounty')]),
     });
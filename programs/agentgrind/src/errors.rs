@@ -64,4 +64,94 @@ pub enum AgentGrindError {
 
     #[msg("Agent already has an active claimed bounty")]
     AgentHasActiveBounty,
+
+    #[msg("Fee basis points exceed the allowed maximum")]
+    InvalidFeeBps,
+
+    #[msg("Token account is not owned by the configured treasury")]
+    InvalidTreasury,
+
+    #[msg("Signer is not the program admin")]
+    UnauthorizedAdmin,
+
+    #[msg("Config parameters are out of range or inconsistent")]
+    InvalidConfig,
+
+    #[msg("X link must be co-signed by the configured verifier")]
+    UnauthorizedVerifier,
+
+    #[msg("Claim work window has not expired yet")]
+    ClaimNotExpired,
+
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Bounty is not in Disputed status")]
+    BountyNotDisputed,
+
+    #[msg("Only the configured arbiter can resolve disputes")]
+    UnauthorizedArbiter,
+
+    #[msg("Split basis points must be between 1 and 9999")]
+    InvalidSplit,
+
+    #[msg("Milestones must be non-zero, at most MAX_MILESTONES, and sum to the amount")]
+    InvalidMilestones,
+
+    #[msg("Instruction does not apply to this bounty kind")]
+    WrongBountyKind,
+
+    #[msg("Contest is being auto-finalized")]
+    ContestFinalizing,
+
+    #[msg("Contest already has entries and must be judged or finalized")]
+    ContestHasEntries,
+
+    #[msg("Contest has no entries")]
+    NoContestEntries,
+
+    #[msg("Winner accounts or prize split are invalid")]
+    InvalidWinners,
+
+    #[msg("Account required for this bounty's currency is missing")]
+    MissingEscrowAccount,
+
+    #[msg("Payout would leave the bounty account below rent exemption")]
+    InsufficientEscrow,
+
+    #[msg("Bounty is not completed or cancelled")]
+    BountyNotFinished,
+
+    #[msg("Review window is outside the configured bounds")]
+    InvalidReviewWindow,
+
+    #[msg("X handle is already linked to another profile")]
+    XHandleTaken,
+
+    #[msg("X handle is not linked to this profile")]
+    XHandleMismatch,
+
+    #[msg("X handle is not linked")]
+    XNotLinked,
+
+    #[msg("X handle was changed too recently")]
+    XChangeCooldown,
+
+    #[msg("New X handle is the same as the current one")]
+    SameXHandle,
+
+    #[msg("Profile has been transferred to another wallet")]
+    ProfileTransferred,
+
+    #[msg("No profile transfer is pending for this wallet")]
+    NoPendingTransfer,
+
+    #[msg("Account layout is not a version this program can migrate")]
+    UnsupportedAccountVersion,
+
+    #[msg("Settle every live bounty before transferring the profile")]
+    LiveBountiesOutstanding,
+
+    #[msg("X handle is empty")]
+    XHandleEmpty,
 }
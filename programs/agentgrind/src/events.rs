@@ -0,0 +1,284 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BountyCurrency, BountyKind, BountyStatus, DisputeRuling};
+
+// ─── Events ────────────────────────────────────────────────────────────────
+// Emitted via `emit!` so indexers can follow bounties without replaying state.
+
+#[event]
+pub struct BountyCreated {
+    pub bounty: Pubkey,
+    pub creator: Pubkey,
+    /// Per-creator sequence number (the PDA seed)
+    pub index: u64,
+    /// Optional label
+    pub bounty_id: String,
+    pub mint: Pubkey,
+    pub currency: BountyCurrency,
+    pub kind: BountyKind,
+    /// Escrowed amount (net of the fee)
+    pub amount: u64,
+    pub fee: u64,
+    /// Per-milestone escrow (empty = single payout)
+    pub milestones: Vec<u64>,
+    pub deadline: i64,
+    pub review_window_secs: i64,
+    pub description_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyClaimed {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub claim_expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProofSubmitted {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub milestone: u8,
+    pub proof_uri: String,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimAbandoned {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+/// Creator approved the current milestone (or the whole bounty) and paid the claimer
+#[event]
+pub struct BountyApproved {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub milestone: u8,
+    pub amount: u64,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyRejected {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub milestone: u8,
+    pub reason: String,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+/// Review window elapsed without a decision; the claimer was paid anyway
+#[event]
+pub struct BountyFinalized {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    /// Whoever cranked finalize_bounty
+    pub caller: Pubkey,
+    pub milestone: u8,
+    pub amount: u64,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BountyCancelled {
+    pub bounty: Pubkey,
+    pub creator: Pubkey,
+    pub refund: u64,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+/// Work window lapsed without proof; the bounty reopened
+#[event]
+pub struct ClaimExpired {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub caller: Pubkey,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub bounty: Pubkey,
+    pub claimer: Pubkey,
+    pub ruling: DisputeRuling,
+    pub agent_amount: u64,
+    pub creator_amount: u64,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+/// Escrow topped up by the creator or a sponsor
+#[event]
+pub struct BountyIncreased {
+    pub bounty: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub new_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Terms changed (extend_deadline, or amend_bounty co-signed by the claimer)
+#[event]
+pub struct BountyAmended {
+    pub bounty: Pubkey,
+    pub deadline: i64,
+    pub amount: u64,
+    pub description_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContestEntrySubmitted {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub agent: Pubkey,
+    pub proof_uri: String,
+    pub entry_count: u32,
+    pub timestamp: i64,
+}
+
+/// Contest paid out, by the creator's judgement or by finalize_contest
+#[event]
+pub struct ContestSettled {
+    pub bounty: Pubkey,
+    pub pool: u64,
+    pub winners: u32,
+    /// True when the creator ghosted and the pool was split evenly
+    pub auto_finalized: bool,
+    pub old_status: BountyStatus,
+    pub new_status: BountyStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct XLinked {
+    pub profile: Pubkey,
+    pub wallet: Pubkey,
+    pub x_handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct XHandleTransferred {
+    pub x_handle: String,
+    pub old_profile: Pubkey,
+    pub new_profile: Pubkey,
+    pub new_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct XUnlinked {
+    pub profile: Pubkey,
+    pub wallet: Pubkey,
+    pub x_handle: String,
+    pub old_reputation: i64,
+    pub new_reputation: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct XRotated {
+    pub profile: Pubkey,
+    pub wallet: Pubkey,
+    pub old_handle: String,
+    pub new_handle: String,
+    pub reputation: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileTransferProposed {
+    pub profile: Pubkey,
+    pub wallet: Pubkey,
+    /// None when a pending proposal is withdrawn
+    pub new_wallet: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileTransferred {
+    pub old_profile: Pubkey,
+    pub new_profile: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+    pub reputation: i64,
+    pub x_handle: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+/// Whose reputation a ReputationChanged event refers to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReputationRole {
+    Creator,
+    Agent,
+}
+
+/// Emitted by every `apply_rep` that moves the score; `delta` is what stuck after
+/// clamping (new - old). `bounty` is the settlement that caused it, None for
+/// recovery and X verification changes.
+#[event]
+pub struct ReputationChanged {
+    pub wallet: Pubkey,
+    pub role: ReputationRole,
+    pub bounty: Option<Pubkey>,
+    pub delta: i64,
+    pub old_reputation: i64,
+    pub new_reputation: i64,
+    pub timestamp: i64,
+}
+
+/// Final snapshot of a bounty before its account is closed
+#[event]
+pub struct BountyClosed {
+    pub bounty: Pubkey,
+    pub creator: Pubkey,
+    pub bounty_id: String,
+    pub mint: Pubkey,
+    /// Completed or Cancelled
+    pub status: BountyStatus,
+    pub amount: u64,
+    pub released_amount: u64,
+    pub claimer: Option<Pubkey>,
+    pub proof_uri: String,
+    pub description_hash: [u8; 32],
+    pub closed_at: i64,
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AgentGrindError;
+use crate::events::ClaimAbandoned;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -19,6 +20,12 @@ pub struct AbandonClaim<'info> {
     )]
     pub agent_profile: Account<'info, AgentProfile>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     pub claimer: Signer<'info>,
 }
 
@@ -26,17 +33,33 @@ pub fn handler(ctx: Context<AbandonClaim>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
     let agent_profile = &mut ctx.accounts.agent_profile;
 
-    // Always unlock agent.
+    // Always unlock agent, and record the walk-away.
     agent_profile.active_bounty = None;
+    agent_profile.total_abandoned += 1;
+    agent_profile.apply_rep(
+        ctx.accounts.config.agent_rep_abandoned,
+        Some(bounty.key()),
+        Clock::get()?.unix_timestamp,
+        &ctx.accounts.config,
+    );
 
     // If the bounty is still in Claimed status, reopen it so someone else can claim.
+    // A submitted milestone stays up for review; paying it reopens the bounty for
+    // the next one (see `Bounty::release_current`).
+    let old_status = bounty.status.clone();
     if bounty.status == BountyStatus::Claimed {
         bounty.status = BountyStatus::Open;
-        bounty.claimer = None;
-        bounty.proof_uri = String::new();
-        bounty.proof_submitted_at = 0;
+        bounty.reset_claim();
     }
 
+    emit!(ClaimAbandoned {
+        bounty: bounty.key(),
+        claimer: ctx.accounts.claimer.key(),
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Claim abandoned by: {}", ctx.accounts.claimer.key());
     Ok(())
 }
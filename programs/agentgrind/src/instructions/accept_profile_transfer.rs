@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::ProfileTransferred;
+use crate::state::*;
+
+/// Step 2 of a key rotation: the proposed wallet accepts and gets a fresh
+/// ["profile", new_wallet] PDA carrying the old profile's reputation, counters
+/// and X link (the XHandle entry is repointed; pass it when the old profile is
+/// verified). The old profile stays behind as a tombstone (`transferred_to`)
+/// that can't post bounties or be linked again. The old wallet's bounties must
+/// all be settled first (`live_bounties == 0`): their outcomes would otherwise
+/// land on the tombstone.
+#[derive(Accounts)]
+pub struct AcceptProfileTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", old_profile.wallet.as_ref()],
+        bump = old_profile.bump,
+        constraint = old_profile.transferred_to.is_none() @ AgentGrindError::ProfileTransferred,
+        constraint = old_profile.pending_transfer == Some(new_wallet.key()) @ AgentGrindError::NoPendingTransfer,
+        constraint = old_profile.live_bounties == 0 @ AgentGrindError::LiveBountiesOutstanding
+    )]
+    pub old_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        init,
+        payer = new_wallet,
+        space = CreatorProfile::MAX_SIZE,
+        seeds = [b"profile", new_wallet.key().as_ref()],
+        bump
+    )]
+    pub new_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"x_handle", &x_handle_seed(&old_profile.x_handle)[..]],
+        bump = x_handle_account.bump,
+        constraint = x_handle_account.profile == old_profile.key() @ AgentGrindError::XHandleMismatch
+    )]
+    pub x_handle_account: Option<Account<'info, XHandle>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub new_wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptProfileTransfer>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let old = &mut ctx.accounts.old_profile;
+    old.recover_rep(now, config);
+
+    // ── Copy reputation, history and the X link ──
+    let new = &mut ctx.accounts.new_profile;
+    new.version = ACCOUNT_VERSION;
+    new.wallet = ctx.accounts.new_wallet.key();
+    new.reputation = old.reputation;
+    new.total_created = old.total_created;
+    new.total_completed = old.total_completed;
+    new.total_rejected = old.total_rejected;
+    new.total_auto_finalized = old.total_auto_finalized;
+    new.total_cancelled = old.total_cancelled;
+    new.total_disputes_lost = old.total_disputes_lost;
+    new.x_handle = old.x_handle.clone();
+    new.x_verified = old.x_verified;
+    new.next_bounty_index = 0;
+    new.small_bounty_streak = old.small_bounty_streak;
+    new.last_rep_update = old.last_rep_update;
+    new.x_changed_at = old.x_changed_at;
+    new.pending_transfer = None;
+    new.transferred_to = None;
+    new.live_bounties = 0;
+    new.bump = ctx.bumps.new_profile;
+
+    // ── Repoint the handle registry ──
+    if old.x_verified {
+        let registry = ctx
+            .accounts
+            .x_handle_account
+            .as_mut()
+            .ok_or(AgentGrindError::XHandleMismatch)?;
+        registry.profile = new.key();
+        registry.wallet = new.wallet;
+    }
+
+    // ── Tombstone the old profile ──
+    old.reputation = 0;
+    old.total_created = 0;
+    old.total_completed = 0;
+    old.total_rejected = 0;
+    old.total_auto_finalized = 0;
+    old.total_cancelled = 0;
+    old.total_disputes_lost = 0;
+    old.x_handle = String::new();
+    old.x_verified = false;
+    old.small_bounty_streak = 0;
+    old.pending_transfer = None;
+    old.transferred_to = Some(new.wallet);
+
+    emit!(ProfileTransferred {
+        old_profile: old.key(),
+        new_profile: new.key(),
+        old_wallet: old.wallet,
+        new_wallet: new.wallet,
+        reputation: new.reputation,
+        x_handle: new.x_handle.clone(),
+        timestamp: now,
+    });
+
+    msg!("Profile transferred: {} -> {}. Rep: {}", old.wallet, new.wallet, new.reputation);
+    Ok(())
+}
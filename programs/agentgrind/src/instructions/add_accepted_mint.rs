@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::errors::AgentGrindError;
+use crate::state::*;
+
+/// Admin-only: allow bounties to escrow `mint` (SPL Token or Token-2022).
+/// Only add mints whose extensions the escrow can live with: transfer fees are
+/// handled, but transfer hooks, permanent delegates or freeze-happy issuers are not.
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = AcceptedMint::MAX_SIZE,
+        seeds = [b"mint", mint.key().as_ref()],
+        bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentGrindError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddAcceptedMint>) -> Result<()> {
+    let accepted_mint = &mut ctx.accounts.accepted_mint;
+    accepted_mint.mint = ctx.accounts.mint.key();
+    accepted_mint.bump = ctx.bumps.accepted_mint;
+
+    msg!("Mint accepted: {}", accepted_mint.mint);
+    Ok(())
+}
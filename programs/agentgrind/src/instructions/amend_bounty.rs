@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::AgentGrindError;
+use crate::events::BountyAmended;
+use crate::state::*;
+use crate::utils::{pay_lamports, required, vault_transfer};
+
+/// Amendment to a Claimed bounty. `None` leaves a term unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AmendBountyParams {
+    /// New deadline; an extension restarts the claimer's work window (capped by it)
+    pub deadline: Option<i64>,
+    /// New (lower) escrow total; the difference is refunded to the creator.
+    /// Raising the reward needs no consent — use increase_bounty.
+    pub amount: Option<u64>,
+    /// New SHA-256 of the off-chain description
+    pub description_hash: Option<[u8; 32]>,
+}
+
+/// Creator changes the terms of a Claimed bounty. Takes effect only with the
+/// claimer's co-signature, so neither side can move the goalposts alone.
+/// Token bounties pass the vault/mint/token accounts when lowering the amount.
+#[derive(Accounts)]
+pub struct AmendBounty<'info> {
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Claimed @ AgentGrindError::BountyNotClaimed,
+        constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
+        constraint = bounty.claimer == Some(claimer.key()) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == bounty.mint,
+        constraint = creator_token_account.owner == creator.key()
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub claimer: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<AmendBounty>, params: AmendBountyParams) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+
+    // ── Deadline (the work window is capped by it, so it moves too) ──
+    if let Some(deadline) = params.deadline {
+        let now = Clock::get()?.unix_timestamp;
+        require!(deadline > now, AgentGrindError::InvalidDeadline);
+        let extended = deadline > bounty.deadline;
+        bounty.deadline = deadline;
+        if extended {
+            // Same window claim_bounty grants: claim_window_secs from now, capped by the deadline
+            bounty.start_work_window(now, &ctx.accounts.config);
+        } else {
+            bounty.claim_expires_at = bounty.claim_expires_at.min(deadline);
+        }
+    }
+
+    // ── Amount (lower only; the cut comes off the final milestone) ──
+    if let Some(amount) = params.amount {
+        require!(amount < bounty.amount, AgentGrindError::InvalidAmount);
+        let refund = bounty.amount - amount;
+        if let Some(last) = bounty.milestones.last_mut() {
+            require!(*last > refund, AgentGrindError::InvalidMilestones);
+            *last -= refund;
+        }
+        require!(amount > bounty.released_amount, AgentGrindError::InvalidAmount);
+
+        if bounty.is_native() {
+            pay_lamports(
+                &bounty.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+                refund,
+            )?;
+        } else {
+            let id_seed = bounty.id_seed();
+            let seeds = &[
+                b"bounty".as_ref(),
+                ctx.accounts.creator.key.as_ref(),
+                id_seed.as_slice(),
+                &[bounty.bump],
+            ];
+            let signer_seeds = &[&seeds[..]];
+            vault_transfer(
+                required(&ctx.accounts.token_program)?,
+                required(&ctx.accounts.vault)?,
+                required(&ctx.accounts.mint)?,
+                required(&ctx.accounts.creator_token_account)?,
+                bounty.to_account_info(),
+                refund,
+                signer_seeds,
+            )?;
+        }
+        bounty.amount = amount;
+    }
+
+    // ── Description ──
+    if let Some(description_hash) = params.description_hash {
+        bounty.description_hash = description_hash;
+    }
+
+    emit!(BountyAmended {
+        bounty: bounty.key(),
+        deadline: bounty.deadline,
+        amount: bounty.amount,
+        description_hash: bounty.description_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Bounty amended: deadline {}, amount {}",
+        bounty.deadline,
+        bounty.amount
+    );
+    Ok(())
+}
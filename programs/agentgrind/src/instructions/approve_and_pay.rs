@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyApproved;
 use crate::state::*;
+use crate::utils::{close_vault, pay_lamports, required, vault_transfer};
 
+/// Token bounties pass vault, mint, claimer_token_account and token_program;
+/// native-SOL bounties pass the `claimer` wallet instead.
 #[derive(Accounts)]
 pub struct ApproveAndPay<'info> {
     #[account(
         mut,
         constraint = bounty.status == BountyStatus::Submitted @ AgentGrindError::BountyNotSubmitted,
         constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
-        seeds = [b"bounty", creator.key().as_ref(), bounty.bounty_id.as_bytes()],
+        seeds = [b"bounty", creator.key().as_ref(), bounty.id_seed().as_slice()],
         bump = bounty.bump
     )]
     pub bounty: Account<'info, Bounty>,
@@ -20,7 +24,11 @@ pub struct ApproveAndPay<'info> {
         seeds = [b"vault", bounty.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -29,63 +37,122 @@ pub struct ApproveAndPay<'info> {
     )]
     pub profile: Account<'info, CreatorProfile>,
 
+    /// Claimer's profile — agent reputation/track record
+    #[account(
+        mut,
+        seeds = [b"agent", agent_profile.wallet.as_ref()],
+        bump = agent_profile.bump,
+        constraint = bounty.claimer == Some(agent_profile.wallet) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         constraint = claimer_token_account.mint == bounty.mint,
         constraint = claimer_token_account.owner == bounty.claimer.unwrap()
     )]
-    pub claimer_token_account: Account<'info, TokenAccount>,
+    pub claimer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: claimer wallet — receives lamports on SOL bounties
+    #[account(
+        mut,
+        constraint = bounty.claimer == Some(claimer.key()) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub claimer: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<ApproveAndPay>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
 
     // ── PDA signer seeds ──
-    let bounty_id = bounty.bounty_id.clone();
+    let id_seed = bounty.id_seed();
     let seeds = &[
         b"bounty".as_ref(),
         ctx.accounts.creator.key.as_ref(),
-        bounty_id.as_bytes(),
+        id_seed.as_slice(),
         &[bounty.bump],
     ];
     let signer_seeds = &[&seeds[..]];
 
-    // ── Transfer vault → claimer ──
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.claimer_token_account.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, bounty.amount)?;
-
-    // ── Close vault (reclaim rent to creator) ──
-    let close_accounts = CloseAccount {
-        account: ctx.accounts.vault.to_account_info(),
-        destination: ctx.accounts.creator.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let close_program = ctx.accounts.token_program.to_account_info();
-    let close_ctx = CpiContext::new_with_signer(close_program, close_accounts, signer_seeds);
-    token::close_account(close_ctx)?;
-
-    // ── Update bounty ──
-    bounty.status = BountyStatus::Completed;
-
-    // ── Reputation: +15 ──
+    // ── Transfer escrow → claimer (current milestone, or everything) ──
+    let payout = bounty.payout_amount();
+    if bounty.is_native() {
+        pay_lamports(
+            &bounty.to_account_info(),
+            &required(&ctx.accounts.claimer)?.to_account_info(),
+            payout,
+        )?;
+    } else {
+        vault_transfer(
+            required(&ctx.accounts.token_program)?,
+            required(&ctx.accounts.vault)?,
+            required(&ctx.accounts.mint)?,
+            required(&ctx.accounts.claimer_token_account)?,
+            bounty.to_account_info(),
+            payout,
+            signer_seeds,
+        )?;
+    }
+
+    // ── Update bounty (advances to the next milestone unless this was the last) ──
+    let config = &ctx.accounts.config;
+    let old_status = bounty.status.clone();
+    let milestone = bounty.current_milestone;
+    let now = Clock::get()?.unix_timestamp;
+    let claimer_active = ctx.accounts.agent_profile.active_bounty == Some(bounty.key());
+    let finished = bounty.release_current(now, config, claimer_active);
+
     let profile = &mut ctx.accounts.profile;
-    profile.apply_rep(REP_COMPLETE);
-    profile.total_completed += 1;
+    profile.recover_rep(now, config);
+    let agent_profile = &mut ctx.accounts.agent_profile;
+    if finished {
+        // ── Close vault (reclaim rent to creator); SOL bounties have none ──
+        if !bounty.is_native() {
+            close_vault(
+                required(&ctx.accounts.token_program)?,
+                required(&ctx.accounts.vault)?,
+                required(&ctx.accounts.mint)?,
+                ctx.accounts.creator.to_account_info(),
+                bounty.to_account_info(),
+                signer_seeds,
+            )?;
+        }
+
+        // ── Reputation: +rep_complete scaled by value (once per bounty, not per milestone) ──
+        profile.apply_completion(bounty.amount, bounty.currency, bounty.key(), now, config);
+        profile.total_completed += 1;
+        profile.live_bounties = profile.live_bounties.saturating_sub(1);
+
+        // ── Agent track record ──
+        agent_profile.record_paid(payout, bounty.key(), now, config);
+    } else {
+        agent_profile.total_earned = agent_profile.total_earned.saturating_add(payout);
+    }
+
+    emit!(BountyApproved {
+        bounty: bounty.key(),
+        claimer: agent_profile.wallet,
+        milestone,
+        amount: payout,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: now,
+    });
 
     msg!(
-        "Bounty approved and paid: {} USDC to {}. Rep now: {}",
-        bounty.amount,
+        "Bounty approved and paid: {} to {}. Rep now: {}",
+        payout,
         bounty.claimer.unwrap(),
         profile.reputation
     );
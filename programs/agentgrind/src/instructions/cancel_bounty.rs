@@ -1,17 +1,22 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyCancelled;
 use crate::state::*;
+use crate::utils::{close_vault, pay_lamports, required, vault_transfer};
 
+/// Token bounties pass vault, mint, creator_token_account and token_program;
+/// native-SOL bounties refund straight to the creator wallet.
 #[derive(Accounts)]
 pub struct CancelBounty<'info> {
     #[account(
         mut,
-        constraint = bounty.status == BountyStatus::Open @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.is_open(Clock::get()?.unix_timestamp, &config) @ AgentGrindError::BountyNotOpen,
         constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
         constraint = bounty.deadline < Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineNotPassed,
-        seeds = [b"bounty", creator.key().as_ref(), bounty.bounty_id.as_bytes()],
+        constraint = bounty.entry_count == 0 @ AgentGrindError::ContestHasEntries,
+        seeds = [b"bounty", creator.key().as_ref(), bounty.id_seed().as_slice()],
         bump = bounty.bump
     )]
     pub bounty: Account<'info, Bounty>,
@@ -21,7 +26,11 @@ pub struct CancelBounty<'info> {
         seeds = [b"vault", bounty.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
@@ -30,62 +39,91 @@ pub struct CancelBounty<'info> {
     )]
     pub profile: Account<'info, CreatorProfile>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         constraint = creator_token_account.mint == bounty.mint,
         constraint = creator_token_account.owner == creator.key()
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<CancelBounty>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
 
     // ── PDA signer seeds ──
-    let bounty_id = bounty.bounty_id.clone();
+    let id_seed = bounty.id_seed();
     let seeds = &[
         b"bounty".as_ref(),
         ctx.accounts.creator.key.as_ref(),
-        bounty_id.as_bytes(),
+        id_seed.as_slice(),
         &[bounty.bump],
     ];
     let signer_seeds = &[&seeds[..]];
 
-    // ── Refund vault → creator ──
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.creator_token_account.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, bounty.amount)?;
-
-    // ── Close vault (rent back to creator) ──
-    let close_accounts = CloseAccount {
-        account: ctx.accounts.vault.to_account_info(),
-        destination: ctx.accounts.creator.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let close_program = ctx.accounts.token_program.to_account_info();
-    let close_ctx = CpiContext::new_with_signer(close_program, close_accounts, signer_seeds);
-    token::close_account(close_ctx)?;
+    // ── Refund escrow → creator, then close the vault (rent back to creator) ──
+    let refund = bounty.remaining_amount();
+    if bounty.is_native() {
+        pay_lamports(
+            &bounty.to_account_info(),
+            &ctx.accounts.creator.to_account_info(),
+            refund,
+        )?;
+    } else {
+        let token_program = required(&ctx.accounts.token_program)?;
+        let vault = required(&ctx.accounts.vault)?;
+        let mint = required(&ctx.accounts.mint)?;
+        vault_transfer(
+            token_program,
+            vault,
+            mint,
+            required(&ctx.accounts.creator_token_account)?,
+            bounty.to_account_info(),
+            refund,
+            signer_seeds,
+        )?;
+        close_vault(
+            token_program,
+            vault,
+            mint,
+            ctx.accounts.creator.to_account_info(),
+            bounty.to_account_info(),
+            signer_seeds,
+        )?;
+    }
 
     // ── Update bounty ──
+    let old_status = bounty.status.clone();
     bounty.status = BountyStatus::Cancelled;
 
     // ── Reputation: 0 (cancel is neutral, no penalty) ──
     let profile = &mut ctx.accounts.profile;
+    profile.recover_rep(Clock::get()?.unix_timestamp, &ctx.accounts.config);
     profile.total_cancelled += 1;
+    profile.live_bounties = profile.live_bounties.saturating_sub(1);
+
+    emit!(BountyCancelled {
+        bounty: bounty.key(),
+        creator: bounty.creator,
+        refund,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!(
-        "Bounty cancelled and refunded: {} USDC. Rep unchanged: {}",
-        bounty.amount,
+        "Bounty cancelled and refunded: {}. Rep unchanged: {}",
+        refund,
         profile.reputation
     );
 
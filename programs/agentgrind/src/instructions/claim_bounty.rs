@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyClaimed;
 use crate::state::*;
 
 #[derive(Accounts)]
 pub struct ClaimBounty<'info> {
     #[account(
         mut,
-        constraint = bounty.status == BountyStatus::Open @ AgentGrindError::BountyNotOpen,
-        constraint = bounty.deadline > Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineExpired
+        constraint = bounty.is_open(Clock::get()?.unix_timestamp, &config) @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.deadline > Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineExpired,
+        constraint = bounty.kind == BountyKind::Standard @ AgentGrindError::WrongBountyKind
     )]
     pub bounty: Account<'info, Bounty>,
 
@@ -21,6 +23,12 @@ pub struct ClaimBounty<'info> {
     )]
     pub agent_profile: Account<'info, AgentProfile>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(mut)]
     pub claimer: Signer<'info>,
 
@@ -33,7 +41,9 @@ pub fn handler(ctx: Context<ClaimBounty>) -> Result<()> {
 
     // Init agent profile if needed
     if agent_profile.wallet == Pubkey::default() {
+        agent_profile.version = ACCOUNT_VERSION;
         agent_profile.wallet = ctx.accounts.claimer.key();
+        agent_profile.reputation = ctx.accounts.config.rep_initial;
         agent_profile.bump = ctx.bumps.agent_profile;
     }
 
@@ -42,14 +52,33 @@ pub fn handler(ctx: Context<ClaimBounty>) -> Result<()> {
         AgentGrindError::AgentHasActiveBounty
     );
 
-    // Set claimer and update status
+    // Set claimer and update status (clears a previous rejected claimer, if any)
+    let old_status = bounty.status.clone();
+    bounty.reset_claim();
     bounty.claimer = Some(ctx.accounts.claimer.key());
     bounty.status = BountyStatus::Claimed;
 
+    let now = Clock::get()?.unix_timestamp;
+    bounty.start_work_window(now, &ctx.accounts.config);
+
     // Lock agent to this bounty until proof is submitted
     agent_profile.active_bounty = Some(bounty.key());
+    agent_profile.total_claimed += 1;
+
+    emit!(BountyClaimed {
+        bounty: bounty.key(),
+        claimer: ctx.accounts.claimer.key(),
+        old_status,
+        new_status: bounty.status.clone(),
+        claim_expires_at: bounty.claim_expires_at,
+        timestamp: now,
+    });
 
-    msg!("Bounty claimed by: {}", ctx.accounts.claimer.key());
+    msg!(
+        "Bounty claimed by: {}, proof due by {}",
+        ctx.accounts.claimer.key(),
+        bounty.claim_expires_at
+    );
 
     Ok(())
 }
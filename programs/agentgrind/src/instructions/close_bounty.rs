@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::BountyClosed;
+use crate::state::*;
+
+/// Creator reclaims the rent of a finished (Completed/Cancelled) bounty.
+/// The vault is already closed by then; a BountyClosed event keeps the history.
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    #[account(
+        mut,
+        close = creator,
+        constraint = matches!(bounty.status, BountyStatus::Completed | BountyStatus::Cancelled)
+            @ AgentGrindError::BountyNotFinished,
+        constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseBounty>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+
+    emit!(BountyClosed {
+        bounty: bounty.key(),
+        creator: bounty.creator,
+        bounty_id: bounty.bounty_id.clone(),
+        mint: bounty.mint,
+        status: bounty.status.clone(),
+        amount: bounty.amount,
+        released_amount: bounty.released_amount,
+        claimer: bounty.claimer,
+        proof_uri: bounty.proof_uri.clone(),
+        description_hash: bounty.description_hash,
+        closed_at: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Bounty closed: {}", bounty.bounty_id);
+    Ok(())
+}
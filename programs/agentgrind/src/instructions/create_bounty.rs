@@ -1,17 +1,32 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyCreated;
 use crate::state::*;
+use crate::utils::{gross_up_for_transfer_fee, required};
 
+/// Token bounties pass the mint, vault and token accounts; native-SOL bounties
+/// omit them all and pass `treasury` instead (lamports stay in the Bounty PDA).
+/// The bounty address is ["bounty", creator, profile.next_bounty_index (u64 LE)].
 #[derive(Accounts)]
-#[instruction(bounty_id: String)]
 pub struct CreateBounty<'info> {
+    /// CreatorProfile — init_if_needed so first-time creators get a profile automatically
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorProfile::MAX_SIZE,
+        seeds = [b"profile", creator.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
     #[account(
         init,
         payer = creator,
         space = Bounty::MAX_SIZE,
-        seeds = [b"bounty", creator.key().as_ref(), bounty_id.as_bytes()],
+        seeds = [b"bounty", creator.key().as_ref(), profile.next_bounty_index.to_le_bytes().as_ref()],
         bump
     )]
     pub bounty: Account<'info, Bounty>,
@@ -21,43 +36,71 @@ pub struct CreateBounty<'info> {
         payer = creator,
         token::mint = mint,
         token::authority = bounty,
+        token::token_program = token_program,
         seeds = [b"vault", bounty.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// CreatorProfile — init_if_needed so first-time creators get a profile automatically
     #[account(
-        init_if_needed,
-        payer = creator,
-        space = CreatorProfile::MAX_SIZE,
-        seeds = [b"profile", creator.key().as_ref()],
-        bump
+        seeds = [b"config"],
+        bump = config.bump
     )]
-    pub profile: Account<'info, CreatorProfile>,
+    pub config: Account<'info, ProgramConfig>,
 
-    pub mint: Account<'info, token::Mint>,
+    /// The cluster's USDC, or any other mint with an AcceptedMint entry
+    #[account(
+        constraint = mint.key() == USDC_MINT || accepted_mint.is_some()
+            @ AgentGrindError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Allowlist entry — only needed for mints other than the cluster's USDC
+    #[account(
+        constraint = Some(accepted_mint.mint) == mint.as_ref().map(|m| m.key()) @ AgentGrindError::InvalidMint
+    )]
+    pub accepted_mint: Option<Account<'info, AcceptedMint>>,
 
     #[account(
         mut,
-        constraint = creator_token_account.mint == mint.key(),
+        constraint = Some(creator_token_account.mint) == mint.as_ref().map(|m| m.key()),
         constraint = creator_token_account.owner == creator.key()
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury token account — receives the fee
+    #[account(
+        mut,
+        constraint = Some(treasury_token_account.mint) == mint.as_ref().map(|m| m.key()) @ AgentGrindError::InvalidMint,
+        constraint = treasury_token_account.owner == config.treasury @ AgentGrindError::InvalidTreasury
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: treasury wallet — receives the fee on SOL bounties
+    #[account(
+        mut,
+        address = config.treasury @ AgentGrindError::InvalidTreasury
+    )]
+    pub treasury: Option<UncheckedAccount<'info>>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<CreateBounty>,
     bounty_id: String,
     amount: u64,
     deadline: i64,
+    milestones: Vec<u64>,
+    kind: BountyKind,
+    description_hash: [u8; 32],
+    review_window_secs: Option<i64>,
 ) -> Result<()> {
     // Validate inputs
     require!(amount > 0, AgentGrindError::InvalidAmount);
@@ -69,34 +112,92 @@ pub fn handler(
         bounty_id.len() <= MAX_BOUNTY_ID_LEN,
         AgentGrindError::BountyIdTooLong
     );
+    require!(
+        milestones.is_empty()
+            || (milestones.len() <= MAX_MILESTONES
+                && milestones.iter().all(|m| *m > 0)
+                && milestones.iter().try_fold(0u64, |acc, m| acc.checked_add(*m)) == Some(amount)),
+        AgentGrindError::InvalidMilestones
+    );
+    // Contests pay winners in one go; milestones only make sense for a single claimer
+    require!(
+        kind == BountyKind::Standard || milestones.is_empty(),
+        AgentGrindError::InvalidMilestones
+    );
+
+    // No mint → native-SOL bounty; contests pay out of a token vault only
+    let currency = if ctx.accounts.mint.is_some() {
+        BountyCurrency::Token
+    } else {
+        BountyCurrency::Sol
+    };
+    require!(
+        kind == BountyKind::Standard || currency == BountyCurrency::Token,
+        AgentGrindError::WrongBountyKind
+    );
+
+    let review_window_secs = review_window_secs.unwrap_or(ctx.accounts.config.review_window_secs);
+    require!(
+        (ctx.accounts.config.min_review_window_secs..=ctx.accounts.config.max_review_window_secs)
+            .contains(&review_window_secs),
+        AgentGrindError::InvalidReviewWindow
+    );
 
     // ── Initialize profile if brand new ──
     let profile = &mut ctx.accounts.profile;
     if profile.wallet == Pubkey::default() {
+        profile.version = ACCOUNT_VERSION;
         profile.wallet = ctx.accounts.creator.key();
-        profile.reputation = REP_INITIAL;
+        profile.reputation = ctx.accounts.config.rep_initial;
         profile.total_created = 0;
         profile.total_completed = 0;
         profile.total_rejected = 0;
         profile.total_auto_finalized = 0;
         profile.total_cancelled = 0;
+        profile.total_disputes_lost = 0;
         profile.x_handle = String::new();
         profile.x_verified = false;
+        profile.next_bounty_index = 0;
+        profile.small_bounty_streak = 0;
+        profile.last_rep_update = 0;
+        profile.x_changed_at = 0;
+        profile.pending_transfer = None;
+        profile.transferred_to = None;
+        profile.live_bounties = 0;
         profile.bump = ctx.bumps.profile;
     }
 
-    // ── Reputation checks ──
-    require!(profile.can_create(), AgentGrindError::ReputationTooLow);
+    // ── Reputation checks (after settling any recovery since the last touch) ──
+    let config = &ctx.accounts.config;
+    require!(profile.transferred_to.is_none(), AgentGrindError::ProfileTransferred);
+    profile.recover_rep(Clock::get()?.unix_timestamp, config);
+    require!(profile.can_create(config, currency), AgentGrindError::ReputationTooLow);
+
+    // ── Fee split: fee → treasury, remainder → escrow ──
+    let fee = config.fee_for(amount);
+    let escrow_amount = amount - fee;
+    require!(escrow_amount > 0, AgentGrindError::InvalidAmount);
+
+    // ── Tier cap applies to the escrow (net), same as increase_bounty ──
     require!(
-        amount <= profile.max_bounty_amount(),
+        escrow_amount <= profile.max_bounty_amount(config, currency),
         AgentGrindError::AmountExceedsRepLimit
     );
 
+    // ── Milestones are posted gross; the fee is taken once over the total
+    //    and the escrow split across them pro rata ──
+    let escrow_milestones = if milestones.is_empty() {
+        Vec::new()
+    } else {
+        net_milestones(&milestones, escrow_amount).ok_or(AgentGrindError::InvalidMilestones)?
+    };
+
     // ── Initialize bounty account ──
     let bounty = &mut ctx.accounts.bounty;
+    bounty.version = ACCOUNT_VERSION;
     bounty.creator = ctx.accounts.creator.key();
-    bounty.mint = ctx.accounts.mint.key();
-    bounty.amount = amount;
+    bounty.mint = ctx.accounts.mint.as_ref().map(|m| m.key()).unwrap_or_default();
+    bounty.amount = escrow_amount;
     bounty.deadline = deadline;
     bounty.status = BountyStatus::Open;
     bounty.claimer = None;
@@ -104,24 +205,106 @@ pub fn handler(
     bounty.proof_submitted_at = 0;
     bounty.rejection_reason = String::new();
     bounty.bounty_id = bounty_id;
+    bounty.seed_scheme = BountySeeds::Indexed;
+    bounty.index = profile.next_bounty_index;
+    bounty.review_window_secs = review_window_secs;
+    bounty.claim_expires_at = 0;
+    bounty.rejected_at = 0;
+    bounty.milestones = escrow_milestones;
+    bounty.current_milestone = 0;
+    bounty.released_amount = 0;
+    bounty.kind = kind;
+    bounty.currency = currency;
+    bounty.entry_count = 0;
+    bounty.entries_paid = 0;
+    bounty.description_hash = description_hash;
     bounty.bump = ctx.bumps.bounty;
 
-    // ── Transfer USDC from creator to vault ──
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.creator_token_account.to_account_info(),
-        to: ctx.accounts.vault.to_account_info(),
-        authority: ctx.accounts.creator.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, amount)?;
+    match currency {
+        BountyCurrency::Token => {
+            let mint = required(&ctx.accounts.mint)?;
+            let vault = required(&ctx.accounts.vault)?;
+            let creator_token_account = required(&ctx.accounts.creator_token_account)?;
+            let token_program = required(&ctx.accounts.token_program)?;
+
+            // ── Transfer fee from creator to treasury ──
+            if fee > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: creator_token_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: required(&ctx.accounts.treasury_token_account)?.to_account_info(),
+                    authority: ctx.accounts.creator.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+                token_interface::transfer_checked(cpi_ctx, fee, mint.decimals)?;
+            }
+
+            // ── Transfer tokens from creator to vault ──
+            // Transfer-fee mints (Token-2022): send enough that the vault nets escrow_amount.
+            let deposit = gross_up_for_transfer_fee(mint, escrow_amount)?;
+            let cpi_accounts = TransferChecked {
+                from: creator_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: ctx.accounts.creator.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+            token_interface::transfer_checked(cpi_ctx, deposit, mint.decimals)?;
+        }
+        BountyCurrency::Sol => {
+            let system_program = ctx.accounts.system_program.to_account_info();
+
+            // ── Transfer fee from creator to treasury wallet ──
+            if fee > 0 {
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: required(&ctx.accounts.treasury)?.to_account_info(),
+                };
+                system_program::transfer(CpiContext::new(system_program.clone(), cpi_accounts), fee)?;
+            }
+
+            // ── Escrow lamports in the bounty PDA (on top of its rent) ──
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.bounty.to_account_info(),
+            };
+            system_program::transfer(CpiContext::new(system_program, cpi_accounts), escrow_amount)?;
+        }
+    }
+
+    if let Some(vault) = ctx.accounts.vault.as_mut() {
+        vault.reload()?;
+        require!(vault.amount >= escrow_amount, AgentGrindError::InvalidAmount);
+    }
 
     // ── Update profile stats ──
     profile.total_created += 1;
+    profile.live_bounties += 1;
+    profile.next_bounty_index += 1;
+
+    let bounty = &ctx.accounts.bounty;
+    emit!(BountyCreated {
+        bounty: bounty.key(),
+        creator: bounty.creator,
+        index: bounty.index,
+        bounty_id: bounty.bounty_id.clone(),
+        mint: bounty.mint,
+        currency,
+        kind,
+        amount: escrow_amount,
+        fee,
+        milestones: bounty.milestones.clone(),
+        deadline,
+        review_window_secs,
+        description_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     msg!(
-        "Bounty created: {} USDC, deadline: {}, rep: {}",
-        amount,
+        "Bounty created: {} {} escrowed, {} fee, deadline: {}, rep: {}",
+        escrow_amount,
+        if currency == BountyCurrency::Sol { "lamports" } else { "token atoms" },
+        fee,
         deadline,
         profile.reputation
     );
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::ClaimExpired;
+use crate::state::*;
+
+/// Anyone (creator or a crank) can release a claim whose work window lapsed
+/// without proof. The agent is unlocked and charged a timeout, and the bounty
+/// reopens. If the deadline has also passed, the creator refunds via `cancel_bounty`.
+#[derive(Accounts)]
+pub struct ExpireClaim<'info> {
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Claimed @ AgentGrindError::BountyNotClaimed,
+        constraint = Clock::get()?.unix_timestamp > bounty.claim_expires_at
+            @ AgentGrindError::ClaimNotExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// The lapsed claimer's profile
+    #[account(
+        mut,
+        seeds = [b"agent", agent_profile.wallet.as_ref()],
+        bump = agent_profile.bump,
+        constraint = bounty.claimer == Some(agent_profile.wallet) @ AgentGrindError::UnauthorizedClaimer,
+        constraint = agent_profile.active_bounty == Some(bounty.key()) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireClaim>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let agent_profile = &mut ctx.accounts.agent_profile;
+
+    // Unlock agent and record the lapse
+    agent_profile.active_bounty = None;
+    agent_profile.total_timed_out += 1;
+    agent_profile.apply_rep(
+        ctx.accounts.config.agent_rep_timed_out,
+        Some(bounty.key()),
+        Clock::get()?.unix_timestamp,
+        &ctx.accounts.config,
+    );
+
+    // Reopen so another agent can claim (or the creator can cancel after the deadline)
+    let old_status = bounty.status.clone();
+    bounty.status = BountyStatus::Open;
+    bounty.reset_claim();
+
+    emit!(ClaimExpired {
+        bounty: bounty.key(),
+        claimer: agent_profile.wallet,
+        caller: ctx.accounts.caller.key(),
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Claim expired for {} by {}",
+        agent_profile.wallet,
+        ctx.accounts.caller.key()
+    );
+    Ok(())
+}
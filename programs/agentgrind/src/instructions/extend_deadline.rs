@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::BountyAmended;
+use crate::state::*;
+
+/// Creator pushes out the deadline of an unclaimed bounty, instead of waiting
+/// it out and cancelling. Works after the old deadline too, as long as the
+/// bounty hasn't been cancelled. Claimed bounties go through amend_bounty.
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    #[account(
+        mut,
+        constraint = bounty.is_open(Clock::get()?.unix_timestamp, &config) @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
+        // Entrants are owed a result; don't let the creator push judging out indefinitely
+        constraint = bounty.entry_count == 0 @ AgentGrindError::ContestHasEntries
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExtendDeadline>, new_deadline: i64) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    require!(
+        new_deadline > bounty.deadline && new_deadline > Clock::get()?.unix_timestamp,
+        AgentGrindError::InvalidDeadline
+    );
+
+    let old_deadline = bounty.deadline;
+    bounty.deadline = new_deadline;
+
+    emit!(BountyAmended {
+        bounty: bounty.key(),
+        deadline: bounty.deadline,
+        amount: bounty.amount,
+        description_hash: bounty.description_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Deadline extended: {} → {}", old_deadline, new_deadline);
+    Ok(())
+}
@@ -1,27 +1,40 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, CloseAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyFinalized;
 use crate::state::*;
+use crate::utils::{close_vault, pay_lamports, required, vault_transfer};
 
-/// Anyone can call this to auto-pay the agent after the 48h review window
-/// has elapsed on a Submitted bounty (creator ghosted).
+/// Anyone can call this to auto-pay the agent after the review window
+/// has elapsed on a Submitted bounty (creator ghosted). Token bounties pass the
+/// vault/mint/token accounts; native-SOL bounties pass the `claimer` wallet.
 #[derive(Accounts)]
 pub struct FinalizeBounty<'info> {
     #[account(
         mut,
         constraint = bounty.status == BountyStatus::Submitted @ AgentGrindError::BountyNotSubmitted,
-        constraint = Clock::get()?.unix_timestamp > bounty.proof_submitted_at + REVIEW_WINDOW_SECS
+        constraint = Clock::get()?.unix_timestamp > bounty.proof_submitted_at + bounty.review_window_secs
             @ AgentGrindError::ReviewWindowActive
     )]
     pub bounty: Account<'info, Bounty>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [b"vault", bounty.key().as_ref()],
         bump
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
     /// Creator's profile — penalised for ghosting
     #[account(
@@ -31,6 +44,15 @@ pub struct FinalizeBounty<'info> {
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
 
+    /// Claimer's profile — agent reputation/track record
+    #[account(
+        mut,
+        seeds = [b"agent", agent_profile.wallet.as_ref()],
+        bump = agent_profile.bump,
+        constraint = bounty.claimer == Some(agent_profile.wallet) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
     /// Rent recipient (the caller pays nothing, gets nothing — just triggers finalize)
     #[account(mut)]
     pub caller: Signer<'info>,
@@ -40,56 +62,112 @@ pub struct FinalizeBounty<'info> {
         constraint = claimer_token_account.mint == bounty.mint,
         constraint = claimer_token_account.owner == bounty.claimer.unwrap()
     )]
-    pub claimer_token_account: Account<'info, TokenAccount>,
+    pub claimer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: claimer wallet — receives lamports on SOL bounties
+    #[account(
+        mut,
+        constraint = bounty.claimer == Some(claimer.key()) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub claimer: Option<UncheckedAccount<'info>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<FinalizeBounty>) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
 
     // ── PDA signer for bounty (needed to sign vault transfer) ──
-    // Bounty PDA seeds: ["bounty", creator, bounty_id]
-    let bounty_id = bounty.bounty_id.clone();
+    // Bounty PDA seeds: ["bounty", creator, id seed] (see Bounty::id_seed)
+    let id_seed = bounty.id_seed();
+    let creator = bounty.creator;
     let seeds = &[
         b"bounty".as_ref(),
-        bounty.creator.as_ref(),
-        bounty_id.as_bytes(),
+        creator.as_ref(),
+        id_seed.as_slice(),
         &[bounty.bump],
     ];
     let signer_seeds = &[&seeds[..]];
 
-    // ── Transfer vault → claimer ──
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.claimer_token_account.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    token::transfer(cpi_ctx, bounty.amount)?;
-
-    // ── Close vault (rent back to caller who triggered finalize) ──
-    let close_accounts = CloseAccount {
-        account: ctx.accounts.vault.to_account_info(),
-        destination: ctx.accounts.caller.to_account_info(),
-        authority: bounty.to_account_info(),
-    };
-    let close_program = ctx.accounts.token_program.to_account_info();
-    let close_ctx = CpiContext::new_with_signer(close_program, close_accounts, signer_seeds);
-    token::close_account(close_ctx)?;
-
-    // ── Update bounty ──
-    bounty.status = BountyStatus::Completed;
-
-    // ── Reputation: -30 (ghost penalty) ──
+    // ── Transfer escrow → claimer (current milestone, or everything) ──
+    let payout = bounty.payout_amount();
+    if bounty.is_native() {
+        pay_lamports(
+            &bounty.to_account_info(),
+            &required(&ctx.accounts.claimer)?.to_account_info(),
+            payout,
+        )?;
+    } else {
+        vault_transfer(
+            required(&ctx.accounts.token_program)?,
+            required(&ctx.accounts.vault)?,
+            required(&ctx.accounts.mint)?,
+            required(&ctx.accounts.claimer_token_account)?,
+            bounty.to_account_info(),
+            payout,
+            signer_seeds,
+        )?;
+    }
+
+    // ── Update bounty (advances to the next milestone unless this was the last) ──
+    let config = &ctx.accounts.config;
+    let old_status = bounty.status.clone();
+    let milestone = bounty.current_milestone;
+    let now = Clock::get()?.unix_timestamp;
+    let claimer_active = ctx.accounts.agent_profile.active_bounty == Some(bounty.key());
+    let finished = bounty.release_current(now, config, claimer_active);
+
+    if finished && !bounty.is_native() {
+        // ── Close vault (rent back to caller who triggered finalize) ──
+        close_vault(
+            required(&ctx.accounts.token_program)?,
+            required(&ctx.accounts.vault)?,
+            required(&ctx.accounts.mint)?,
+            ctx.accounts.caller.to_account_info(),
+            bounty.to_account_info(),
+            signer_seeds,
+        )?;
+    }
+
+    // ── Reputation: ghost penalty weighted by the review window and the ghosted payout
+    //    (every ghosted milestone counts) ──
     let profile = &mut ctx.accounts.creator_profile;
-    profile.apply_rep(REP_GHOST);
+    profile.recover_rep(now, config);
+    profile.apply_penalty(
+        config.ghost_penalty(bounty.review_window_secs),
+        payout,
+        bounty.currency,
+        bounty.key(),
+        now,
+        config,
+    );
     profile.total_auto_finalized += 1;
+    if finished {
+        profile.live_bounties = profile.live_bounties.saturating_sub(1);
+    }
+
+    // ── Agent track record (delivered; the ghosting is on the creator) ──
+    let agent_profile = &mut ctx.accounts.agent_profile;
+    if finished {
+        agent_profile.record_paid(payout, bounty.key(), now, config);
+    } else {
+        agent_profile.total_earned = agent_profile.total_earned.saturating_add(payout);
+    }
+
+    emit!(BountyFinalized {
+        bounty: bounty.key(),
+        claimer: agent_profile.wallet,
+        caller: ctx.accounts.caller.key(),
+        milestone,
+        amount: payout,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: now,
+    });
 
     msg!(
-        "Bounty auto-finalized (creator ghosted). {} USDC paid to {}. Creator rep now: {}",
-        bounty.amount,
+        "Bounty auto-finalized (creator ghosted). {} paid to {}. Creator rep now: {}",
+        payout,
         bounty.claimer.unwrap(),
         profile.reputation
     );
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::AgentGrindError;
+use crate::events::ContestSettled;
+use crate::state::*;
+use crate::utils::close_vault;
+use super::judge_contest::pay_entries;
+
+/// Anyone can call this once the review window after a contest's deadline has
+/// elapsed without judging (creator ghosted). The escrow is split evenly across
+/// every entry. Entries are paid in batches — as many (submission, token account,
+/// agent profile) triples in `remaining_accounts` as fit in a transaction — and
+/// the call that pays the last one closes the vault and applies the ghost penalty.
+#[derive(Accounts)]
+pub struct FinalizeContest<'info> {
+    #[account(
+        mut,
+        constraint = bounty.kind == BountyKind::Contest @ AgentGrindError::WrongBountyKind,
+        constraint = bounty.status == BountyStatus::Open @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.entry_count > 0 @ AgentGrindError::NoContestEntries,
+        constraint = Clock::get()?.unix_timestamp > bounty.deadline + bounty.review_window_secs
+            @ AgentGrindError::ReviewWindowActive
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Creator's profile — penalised for ghosting
+    #[account(
+        mut,
+        seeds = [b"profile", bounty.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Rent recipient for the closed vault
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeContest<'info>>) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+
+    // ── Even split across all entries; the last one paid absorbs rounding ──
+    let batch = ctx.remaining_accounts.len() / 3;
+    let unpaid = (bounty.entry_count - bounty.entries_paid) as usize;
+    require!(batch > 0 && batch <= unpaid, AgentGrindError::InvalidWinners);
+    let share = bounty.amount / bounty.entry_count as u64;
+    let mut shares = vec![share; batch];
+    let settles = batch == unpaid;
+    if settles {
+        shares[batch - 1] = bounty.remaining_amount() - share * (batch as u64 - 1);
+    }
+
+    pay_entries(
+        bounty,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &shares,
+        &ctx.accounts.config,
+    )?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.entries_paid += batch as u32;
+    bounty.released_amount += shares.iter().sum::<u64>();
+    if !settles {
+        msg!(
+            "Contest auto-finalizing: {}/{} entries paid",
+            bounty.entries_paid,
+            bounty.entry_count
+        );
+        return Ok(());
+    }
+
+    // ── Close vault (rent back to caller who paid the last batch) ──
+    let id_seed = bounty.id_seed();
+    let seeds = &[
+        b"bounty".as_ref(),
+        bounty.creator.as_ref(),
+        id_seed.as_slice(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    close_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        ctx.accounts.caller.to_account_info(),
+        bounty.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // ── Update bounty ──
+    let old_status = bounty.status.clone();
+    bounty.status = BountyStatus::Completed;
+    let pool = bounty.amount;
+
+    // ── Reputation: ghost penalty weighted by the review window and the pool ──
+    let config = &ctx.accounts.config;
+    let profile = &mut ctx.accounts.creator_profile;
+    let now = Clock::get()?.unix_timestamp;
+    profile.recover_rep(now, config);
+    profile.apply_penalty(
+        config.ghost_penalty(bounty.review_window_secs),
+        pool,
+        bounty.currency,
+        bounty.key(),
+        now,
+        config,
+    );
+    profile.total_auto_finalized += 1;
+    profile.live_bounties = profile.live_bounties.saturating_sub(1);
+
+    emit!(ContestSettled {
+        bounty: bounty.key(),
+        pool,
+        winners: bounty.entry_count,
+        auto_finalized: true,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Contest auto-finalized (creator ghosted). {} token atoms split across {} entries. Creator rep now: {}",
+        pool,
+        bounty.entry_count,
+        profile.reputation
+    );
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::AgentGrindError;
+use crate::events::BountyIncreased;
+use crate::state::*;
+use crate::utils::{gross_up_for_transfer_fee, required};
+
+/// Anyone (the creator or a sponsor) tops up an Open or Claimed bounty before its deadline.
+/// Contests stop taking top-ups once finalize_contest has paid an entry, so every
+/// batch splits the same pot.
+/// `amount` is gross like in create_bounty: the platform fee goes to the treasury
+/// and the rest is added to escrow (and to the final milestone, if any).
+/// Token bounties pass the vault/mint/token accounts; SOL bounties pass `treasury`.
+#[derive(Accounts)]
+pub struct IncreaseBounty<'info> {
+    #[account(
+        mut,
+        constraint = bounty.is_open(Clock::get()?.unix_timestamp, &config)
+            || bounty.status == BountyStatus::Claimed @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.deadline > Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineExpired,
+        constraint = bounty.entries_paid == 0 @ AgentGrindError::ContestFinalizing
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Creator's profile — the cap applies to the bounty, whoever funds it
+    #[account(
+        mut,
+        seeds = [b"profile", bounty.creator.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        constraint = sponsor_token_account.mint == bounty.mint @ AgentGrindError::InvalidMint,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Platform treasury token account — receives the fee
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == bounty.mint @ AgentGrindError::InvalidMint,
+        constraint = treasury_token_account.owner == config.treasury @ AgentGrindError::InvalidTreasury
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: treasury wallet — receives the fee on SOL bounties
+    #[account(
+        mut,
+        address = config.treasury @ AgentGrindError::InvalidTreasury
+    )]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<IncreaseBounty>, amount: u64) -> Result<()> {
+    require!(amount > 0, AgentGrindError::InvalidAmount);
+
+    // ── Fee split: fee → treasury, remainder → escrow ──
+    let config = &ctx.accounts.config;
+    let fee = config.fee_for(amount);
+    let escrow_amount = amount - fee;
+    require!(escrow_amount > 0, AgentGrindError::InvalidAmount);
+
+    // ── Reputation cap: the new escrow total (net, like create_bounty) must still fit the tier ──
+    let profile = &mut ctx.accounts.profile;
+    profile.recover_rep(Clock::get()?.unix_timestamp, config);
+    let bounty = &mut ctx.accounts.bounty;
+    let new_total = bounty
+        .amount
+        .checked_add(escrow_amount)
+        .ok_or(AgentGrindError::InvalidAmount)?;
+    require!(
+        new_total <= profile.max_bounty_amount(config, bounty.currency),
+        AgentGrindError::AmountExceedsRepLimit
+    );
+
+    if bounty.is_native() {
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        // ── Transfer fee from sponsor to treasury wallet ──
+        if fee > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.sponsor.to_account_info(),
+                to: required(&ctx.accounts.treasury)?.to_account_info(),
+            };
+            system_program::transfer(CpiContext::new(system_program.clone(), cpi_accounts), fee)?;
+        }
+
+        // ── Escrow lamports in the bounty PDA ──
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.sponsor.to_account_info(),
+            to: bounty.to_account_info(),
+        };
+        system_program::transfer(CpiContext::new(system_program, cpi_accounts), escrow_amount)?;
+    } else {
+        let mint = required(&ctx.accounts.mint)?;
+        let sponsor_token_account = required(&ctx.accounts.sponsor_token_account)?;
+        let token_program = required(&ctx.accounts.token_program)?;
+
+        // ── Transfer fee from sponsor to treasury ──
+        if fee > 0 {
+            let cpi_accounts = TransferChecked {
+                from: sponsor_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: required(&ctx.accounts.treasury_token_account)?.to_account_info(),
+                authority: ctx.accounts.sponsor.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+            token_interface::transfer_checked(cpi_ctx, fee, mint.decimals)?;
+        }
+
+        // ── Transfer tokens from sponsor to vault (grossed up for transfer fees) ──
+        let deposit = gross_up_for_transfer_fee(mint, escrow_amount)?;
+        let cpi_accounts = TransferChecked {
+            from: sponsor_token_account.to_account_info(),
+            mint: mint.to_account_info(),
+            to: required(&ctx.accounts.vault)?.to_account_info(),
+            authority: ctx.accounts.sponsor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, deposit, mint.decimals)?;
+    }
+
+    // ── Update bounty: the top-up rides on the final milestone ──
+    bounty.amount += escrow_amount;
+    if let Some(last) = bounty.milestones.last_mut() {
+        *last += escrow_amount;
+    }
+
+    if let Some(vault) = ctx.accounts.vault.as_mut() {
+        vault.reload()?;
+        require!(
+            vault.amount >= bounty.remaining_amount(),
+            AgentGrindError::InvalidAmount
+        );
+    }
+
+    emit!(BountyIncreased {
+        bounty: bounty.key(),
+        sponsor: ctx.accounts.sponsor.key(),
+        amount: escrow_amount,
+        fee,
+        new_amount: bounty.amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Bounty increased by {} ({} fee) by {}. New total: {}",
+        escrow_amount,
+        fee,
+        ctx.accounts.sponsor.key(),
+        bounty.amount
+    );
+
+    Ok(())
+}
@@ -15,6 +15,12 @@ pub struct InitProfile<'info> {
     )]
     pub profile: Account<'info, CreatorProfile>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -24,15 +30,24 @@ pub struct InitProfile<'info> {
 
 pub fn handler(ctx: Context<InitProfile>) -> Result<()> {
     let profile = &mut ctx.accounts.profile;
+    profile.version = ACCOUNT_VERSION;
     profile.wallet = ctx.accounts.authority.key();
-    profile.reputation = REP_INITIAL;
+    profile.reputation = ctx.accounts.config.rep_initial;
     profile.total_created = 0;
     profile.total_completed = 0;
     profile.total_rejected = 0;
     profile.total_auto_finalized = 0;
     profile.total_cancelled = 0;
+    profile.total_disputes_lost = 0;
     profile.x_handle = String::new();
     profile.x_verified = false;
+    profile.next_bounty_index = 0;
+    profile.small_bounty_streak = 0;
+    profile.last_rep_update = Clock::get()?.unix_timestamp;
+    profile.x_changed_at = 0;
+    profile.pending_transfer = None;
+    profile.transferred_to = None;
+    profile.live_bounties = 0;
     profile.bump = ctx.bumps.profile;
 
     msg!("CreatorProfile initialized for {}", profile.wallet);
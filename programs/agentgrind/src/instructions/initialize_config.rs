@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::program::Agentgrind;
+use crate::state::*;
+
+/// One-time setup of the singleton ProgramConfig (reputation/limits start at the defaults).
+/// Only the program upgrade authority can call this, so nobody can front-run
+/// the deploy and install their own treasury.
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramConfig::MAX_SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+    )]
+    pub program: Program<'info, Agentgrind>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ AgentGrindError::UnauthorizedAdmin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeConfig>, treasury: Pubkey, fee_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.version = CONFIG_VERSION;
+    config.admin = ctx.accounts.admin.key();
+    config.treasury = treasury;
+    // Admin verifies X links until the API key is installed via update_config
+    config.x_verifier = ctx.accounts.admin.key();
+    // Likewise for disputes until a dedicated arbiter is configured
+    config.arbiter = ctx.accounts.admin.key();
+    config.set_defaults();
+    config.fee_bps = fee_bps;
+    config.bump = ctx.bumps.config;
+    config.validate()?;
+
+    msg!("Config initialized: treasury {}, fee {} bps", treasury, fee_bps);
+    Ok(())
+}
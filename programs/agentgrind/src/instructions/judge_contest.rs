@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::AgentGrindError;
+use crate::events::ContestSettled;
+use crate::state::*;
+use crate::utils::close_vault;
+
+/// Creator picks contest winners after the deadline and splits the prize
+/// (e.g. 6000/3000/1000 bps). Winners are passed in `remaining_accounts` as
+/// (submission, winner token account, winner agent profile) triples, in the
+/// same order as `prize_bps`. Only the winners are paid, however many entered.
+#[derive(Accounts)]
+pub struct JudgeContest<'info> {
+    #[account(
+        mut,
+        constraint = bounty.kind == BountyKind::Contest @ AgentGrindError::WrongBountyKind,
+        constraint = bounty.status == BountyStatus::Open @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
+        constraint = bounty.deadline < Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineNotPassed,
+        constraint = bounty.entry_count > 0 @ AgentGrindError::NoContestEntries,
+        constraint = bounty.entries_paid == 0 @ AgentGrindError::ContestFinalizing,
+        seeds = [b"bounty", creator.key().as_ref(), bounty.id_seed().as_slice()],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", creator.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, JudgeContest<'info>>,
+    prize_bps: Vec<u16>,
+) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    require!(
+        !prize_bps.is_empty()
+            && prize_bps.len() <= MAX_CONTEST_WINNERS
+            && prize_bps.len() <= bounty.entry_count as usize
+            && prize_bps.iter().all(|b| *b > 0)
+            && prize_bps.iter().map(|b| *b as u32).sum::<u32>() == BPS_DENOMINATOR as u32,
+        AgentGrindError::InvalidWinners
+    );
+
+    // ── Shares of the escrow; the last winner absorbs rounding ──
+    let pool = bounty.remaining_amount();
+    let mut shares: Vec<u64> = prize_bps
+        .iter()
+        .map(|b| (pool as u128 * *b as u128 / BPS_DENOMINATOR as u128) as u64)
+        .collect();
+    let allocated: u64 = shares[..shares.len() - 1].iter().sum();
+    *shares.last_mut().unwrap() = pool - allocated;
+
+    pay_entries(
+        bounty,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        &shares,
+        &ctx.accounts.config,
+    )?;
+
+    // ── Close vault (reclaim rent to creator) ──
+    let id_seed = bounty.id_seed();
+    let seeds = &[
+        b"bounty".as_ref(),
+        bounty.creator.as_ref(),
+        id_seed.as_slice(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    close_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        ctx.accounts.creator.to_account_info(),
+        bounty.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // ── Update bounty ──
+    let bounty = &mut ctx.accounts.bounty;
+    let old_status = bounty.status.clone();
+    bounty.status = BountyStatus::Completed;
+    bounty.released_amount = bounty.amount;
+
+    // ── Reputation: +rep_complete scaled by value ──
+    let config = &ctx.accounts.config;
+    let profile = &mut ctx.accounts.profile;
+    let now = Clock::get()?.unix_timestamp;
+    profile.recover_rep(now, config);
+    profile.apply_completion(bounty.amount, bounty.currency, bounty.key(), now, config);
+    profile.total_completed += 1;
+    profile.live_bounties = profile.live_bounties.saturating_sub(1);
+
+    emit!(ContestSettled {
+        bounty: bounty.key(),
+        pool,
+        winners: shares.len() as u32,
+        auto_finalized: false,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Contest judged: {} token atoms to {} winner(s). Rep now: {}",
+        pool,
+        shares.len(),
+        profile.reputation
+    );
+
+    Ok(())
+}
+
+/// Pay `shares[i]` from the vault to the i-th (submission, token account,
+/// agent profile) triple in `entries`, recording the prize on the Submission
+/// and the payout on the AgentProfile. Each Submission can be paid only once.
+pub(crate) fn pay_entries<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    entries: &'info [AccountInfo<'info>],
+    shares: &[u64],
+    config: &ProgramConfig,
+) -> Result<()> {
+    require!(
+        entries.len() == shares.len() * 3,
+        AgentGrindError::InvalidWinners
+    );
+
+    let id_seed = bounty.id_seed();
+    let seeds = &[
+        b"bounty".as_ref(),
+        bounty.creator.as_ref(),
+        id_seed.as_slice(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let now = Clock::get()?.unix_timestamp;
+
+    for (entry, share) in entries.chunks_exact(3).zip(shares) {
+        require!(*share > 0, AgentGrindError::InvalidWinners);
+
+        let mut submission: Account<'info, Submission> = Account::try_from(&entry[0])?;
+        require_keys_eq!(submission.bounty, bounty.key(), AgentGrindError::InvalidWinners);
+        require!(submission.prize == 0, AgentGrindError::InvalidWinners);
+
+        let winner_token_account: InterfaceAccount<'info, TokenAccount> =
+            InterfaceAccount::try_from(&entry[1])?;
+        require!(
+            winner_token_account.mint == bounty.mint
+                && winner_token_account.owner == submission.agent,
+            AgentGrindError::InvalidWinners
+        );
+
+        let mut agent_profile: Account<'info, AgentProfile> = Account::try_from(&entry[2])?;
+        require_keys_eq!(agent_profile.wallet, submission.agent, AgentGrindError::InvalidWinners);
+
+        // ── Transfer vault → winner ──
+        let cpi_accounts = TransferChecked {
+            from: vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: winner_token_account.to_account_info(),
+            authority: bounty.to_account_info(),
+        };
+        let cpi_program = token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token_interface::transfer_checked(cpi_ctx, *share, mint.decimals)?;
+
+        // Persist immediately so a duplicate entry in the list fails the prize check
+        submission.prize = *share;
+        submission.exit(&crate::ID)?;
+        agent_profile.record_paid(*share, bounty.key(), now, config);
+        agent_profile.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
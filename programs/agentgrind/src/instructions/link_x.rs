@@ -1,23 +1,53 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AgentGrindError;
+use crate::events::XLinked;
 use crate::state::*;
 
 /// Links an X (Twitter) handle to a CreatorProfile.
-/// The actual X verification (OAuth / signature check) happens off-chain in the API.
-/// The API calls this instruction only AFTER verifying the handle is real.
+/// The actual X verification (OAuth) happens off-chain in the API, which then
+/// co-signs this instruction with the `config.x_verifier` key. Without that
+/// signature the profile owner can't mark themselves verified.
 /// `authority` here is the wallet that owns the profile (must sign).
+/// The handle is registered in an XHandle PDA; linking fails if another
+/// profile already owns it (see `transfer_x_handle` for wallet rotations).
 #[derive(Accounts)]
+#[instruction(x_handle: String)]
 pub struct LinkX<'info> {
     #[account(
         mut,
         seeds = [b"profile", authority.key().as_ref()],
         bump,
-        constraint = !profile.x_verified @ AgentGrindError::XAlreadyVerified
+        constraint = !profile.x_verified @ AgentGrindError::XAlreadyVerified,
+        constraint = profile.transferred_to.is_none() @ AgentGrindError::ProfileTransferred
     )]
     pub profile: Account<'info, CreatorProfile>,
 
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = XHandle::MAX_SIZE,
+        seeds = [b"x_handle", &x_handle_seed(&x_handle)[..]],
+        bump
+    )]
+    pub x_handle_account: Account<'info, XHandle>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    /// Trusted verifier (the API) attesting that `authority` owns `x_handle`
+    #[account(
+        constraint = verifier.key() == config.x_verifier @ AgentGrindError::UnauthorizedVerifier
+    )]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<LinkX>, x_handle: String) -> Result<()> {
@@ -25,11 +55,40 @@ pub fn handler(ctx: Context<LinkX>, x_handle: String) -> Result<()> {
         x_handle.len() <= MAX_X_HANDLE_LEN,
         AgentGrindError::XHandleTooLong
     );
-    require!(!x_handle.is_empty(), AgentGrindError::XHandleTooLong);
+    require!(
+        !normalize_x_handle(&x_handle).is_empty(),
+        AgentGrindError::XHandleEmpty
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.profile.can_change_x(now, &ctx.accounts.config),
+        AgentGrindError::XChangeCooldown
+    );
 
+    // ── One profile per handle ──
+    let registry = &mut ctx.accounts.x_handle_account;
     let profile = &mut ctx.accounts.profile;
+    require!(
+        registry.profile == Pubkey::default() || registry.profile == profile.key(),
+        AgentGrindError::XHandleTaken
+    );
+    registry.handle = normalize_x_handle(&x_handle);
+    registry.profile = profile.key();
+    registry.wallet = ctx.accounts.authority.key();
+    registry.bump = ctx.bumps.x_handle_account;
+
+    profile.recover_rep(now, &ctx.accounts.config);
     profile.x_handle = x_handle.clone();
-    profile.x_verified = true;
+    profile.set_x_verified(true, now, &ctx.accounts.config);
+    profile.x_changed_at = now;
+
+    emit!(XLinked {
+        profile: profile.key(),
+        wallet: ctx.accounts.authority.key(),
+        x_handle: x_handle.clone(),
+        timestamp: now,
+    });
 
     msg!("X handle linked: @{}", x_handle);
 
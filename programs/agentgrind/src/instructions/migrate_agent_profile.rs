@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::AccountMigrated;
+use crate::state::*;
+use crate::utils::rewrite_account;
+
+/// Upgrades a v1 (unversioned) AgentProfile in place, like `migrate_bounty`.
+#[derive(Accounts)]
+pub struct MigrateAgentProfile<'info> {
+    /// CHECK: a v1 account can't be loaded as `Account<AgentProfile>`; the owner is
+    /// checked here, the discriminator and v1 size in `AgentProfile::from_v1`
+    #[account(mut, owner = crate::ID @ AgentGrindError::UnsupportedAccountVersion)]
+    pub agent_profile: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateAgentProfile>) -> Result<()> {
+    let info = ctx.accounts.agent_profile.to_account_info();
+    let profile = AgentProfile::from_v1(&info.try_borrow_data()?, &ctx.accounts.config)?;
+
+    rewrite_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &profile,
+        AgentProfile::MAX_SIZE,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version: 1,
+        to_version: ACCOUNT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("AgentProfile {} migrated to v{}", info.key(), ACCOUNT_VERSION);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::AccountMigrated;
+use crate::state::*;
+use crate::utils::rewrite_account;
+
+/// Upgrades a v1 (unversioned) Bounty in place: reallocs it to the current size
+/// (`payer` tops up rent) and rewrites it in the current layout. Permissionless,
+/// since the result is fully determined by the old account.
+#[derive(Accounts)]
+pub struct MigrateBounty<'info> {
+    /// CHECK: a v1 account can't be loaded as `Account<Bounty>`; the owner is checked
+    /// here, the discriminator and v1 size in `Bounty::from_v1`
+    #[account(mut, owner = crate::ID @ AgentGrindError::UnsupportedAccountVersion)]
+    pub bounty: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateBounty>) -> Result<()> {
+    let info = ctx.accounts.bounty.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+    let bounty = Bounty::from_v1(&info.try_borrow_data()?, now, &ctx.accounts.config)?;
+
+    rewrite_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &bounty,
+        Bounty::MAX_SIZE,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version: 1,
+        to_version: ACCOUNT_VERSION,
+        timestamp: now,
+    });
+
+    msg!("Bounty {} migrated to v{}", info.key(), ACCOUNT_VERSION);
+    Ok(())
+}
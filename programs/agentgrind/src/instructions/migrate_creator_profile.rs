@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::AccountMigrated;
+use crate::state::*;
+use crate::utils::rewrite_account;
+
+/// Upgrades a v1 (unversioned) CreatorProfile in place, like `migrate_bounty`.
+/// v1 X links were self-attested, so no handle is registered here: the profile
+/// comes out unverified and its owner re-links through `link_x`.
+#[derive(Accounts)]
+pub struct MigrateCreatorProfile<'info> {
+    /// CHECK: a v1 account can't be loaded as `Account<CreatorProfile>`; the owner is
+    /// checked here, the discriminator and v1 size in `CreatorProfile::from_v1`
+    #[account(mut, owner = crate::ID @ AgentGrindError::UnsupportedAccountVersion)]
+    pub profile: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateCreatorProfile>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let info = ctx.accounts.profile.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+    let profile = CreatorProfile::from_v1(&info.try_borrow_data()?, now, config)?;
+
+    rewrite_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &profile,
+        CreatorProfile::MAX_SIZE,
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version: 1,
+        to_version: ACCOUNT_VERSION,
+        timestamp: now,
+    });
+
+    msg!("CreatorProfile {} migrated to v{}", info.key(), ACCOUNT_VERSION);
+    Ok(())
+}
+
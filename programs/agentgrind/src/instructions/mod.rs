@@ -1,3 +1,5 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod create_bounty;
 pub mod init_profile;
 pub mod claim_bounty;
@@ -8,6 +10,28 @@ pub mod cancel_bounty;
 pub mod reject_bounty;
 pub mod finalize_bounty;
 pub mod link_x;
+pub mod initialize_config;
+pub mod update_config;
+pub mod expire_claim;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod submit_entry;
+pub mod judge_contest;
+pub mod finalize_contest;
+pub mod add_accepted_mint;
+pub mod remove_accepted_mint;
+pub mod increase_bounty;
+pub mod extend_deadline;
+pub mod amend_bounty;
+pub mod close_bounty;
+pub mod transfer_x_handle;
+pub mod unlink_x;
+pub mod rotate_x;
+pub mod propose_profile_transfer;
+pub mod accept_profile_transfer;
+pub mod migrate_bounty;
+pub mod migrate_creator_profile;
+pub mod migrate_agent_profile;
 
 pub use create_bounty::*;
 pub use init_profile::*;
@@ -19,3 +43,25 @@ pub use cancel_bounty::*;
 pub use reject_bounty::*;
 pub use finalize_bounty::*;
 pub use link_x::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use expire_claim::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use submit_entry::*;
+pub use judge_contest::*;
+pub use finalize_contest::*;
+pub use add_accepted_mint::*;
+pub use remove_accepted_mint::*;
+pub use increase_bounty::*;
+pub use extend_deadline::*;
+pub use amend_bounty::*;
+pub use close_bounty::*;
+pub use transfer_x_handle::*;
+pub use unlink_x::*;
+pub use rotate_x::*;
+pub use propose_profile_transfer::*;
+pub use accept_profile_transfer::*;
+pub use migrate_bounty::*;
+pub use migrate_creator_profile::*;
+pub use migrate_agent_profile::*;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::DisputeOpened;
+use crate::state::*;
+
+/// Claimer contests a rejection within the dispute window.
+/// The bounty moves to Disputed and the vault stays locked until the arbiter rules.
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Rejected @ AgentGrindError::BountyNotRejected,
+        constraint = bounty.claimer == Some(claimer.key()) @ AgentGrindError::UnauthorizedClaimer,
+        constraint = Clock::get()?.unix_timestamp <= bounty.rejected_at + config.dispute_window_secs
+            @ AgentGrindError::DisputeWindowClosed
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub claimer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<OpenDispute>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let old_status = bounty.status.clone();
+    bounty.status = BountyStatus::Disputed;
+
+    emit!(DisputeOpened {
+        bounty: bounty.key(),
+        claimer: ctx.accounts.claimer.key(),
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Rejection disputed by: {}", ctx.accounts.claimer.key());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::ProfileTransferProposed;
+use crate::state::*;
+
+/// Step 1 of a key rotation: the profile owner names the wallet that may take
+/// over the profile. Proposing again replaces the pending wallet; `None` withdraws it.
+#[derive(Accounts)]
+pub struct ProposeProfileTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", authority.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.transferred_to.is_none() @ AgentGrindError::ProfileTransferred
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ProposeProfileTransfer>, new_wallet: Option<Pubkey>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.pending_transfer = new_wallet;
+
+    emit!(ProfileTransferProposed {
+        profile: profile.key(),
+        wallet: profile.wallet,
+        new_wallet,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    match new_wallet {
+        Some(wallet) => msg!("Profile transfer proposed: {} -> {}", profile.wallet, wallet),
+        None => msg!("Profile transfer withdrawn for {}", profile.wallet),
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AgentGrindError;
+use crate::events::BountyRejected;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -9,7 +10,7 @@ pub struct RejectBounty<'info> {
         mut,
         constraint = bounty.status == BountyStatus::Submitted @ AgentGrindError::BountyNotSubmitted,
         constraint = bounty.creator == creator.key() @ AgentGrindError::UnauthorizedCreator,
-        seeds = [b"bounty", creator.key().as_ref(), bounty.bounty_id.as_bytes()],
+        seeds = [b"bounty", creator.key().as_ref(), bounty.id_seed().as_slice()],
         bump = bounty.bump
     )]
     pub bounty: Account<'info, Bounty>,
@@ -21,6 +22,21 @@ pub struct RejectBounty<'info> {
     )]
     pub profile: Account<'info, CreatorProfile>,
 
+    /// Claimer's profile — agent reputation/track record
+    #[account(
+        mut,
+        seeds = [b"agent", agent_profile.wallet.as_ref()],
+        bump = agent_profile.bump,
+        constraint = bounty.claimer == Some(agent_profile.wallet) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     pub creator: Signer<'info>,
 }
 
@@ -32,18 +48,51 @@ pub fn handler(ctx: Context<RejectBounty>, reason: String) -> Result<()> {
 
     let bounty = &mut ctx.accounts.bounty;
 
-    // Reopens the bounty so another agent can claim
-    bounty.status = BountyStatus::Open;
+    // Claimer keeps the claim (and proof) for the dispute window; after that
+    // the bounty counts as Open and the next claim clears it.
+    let old_status = bounty.status.clone();
+    bounty.status = BountyStatus::Rejected;
     bounty.rejection_reason = reason.clone();
-    bounty.claimer = None;
-    bounty.proof_uri = String::new();
-    bounty.proof_submitted_at = 0;
+    bounty.rejected_at = Clock::get()?.unix_timestamp;
 
-    // ── Reputation: -15 ──
+    // ── Reputation: rep_reject scaled by the rejected payout ──
+    let config = &ctx.accounts.config;
     let profile = &mut ctx.accounts.profile;
-    profile.apply_rep(REP_REJECT);
+    profile.recover_rep(bounty.rejected_at, config);
+    profile.apply_penalty(
+        config.rep_reject,
+        bounty.payout_amount(),
+        bounty.currency,
+        bounty.key(),
+        bounty.rejected_at,
+        config,
+    );
     profile.total_rejected += 1;
 
+    // ── Agent track record ──
+    let agent_profile = &mut ctx.accounts.agent_profile;
+    if agent_profile.active_bounty == Some(bounty.key()) {
+        // Mid-milestone rejection: the agent was still locked to this bounty
+        agent_profile.active_bounty = None;
+    }
+    agent_profile.total_rejected += 1;
+    agent_profile.apply_rep(
+        ctx.accounts.config.agent_rep_rejected,
+        Some(bounty.key()),
+        bounty.rejected_at,
+        &ctx.accounts.config,
+    );
+
+    emit!(BountyRejected {
+        bounty: bounty.key(),
+        claimer: agent_profile.wallet,
+        milestone: bounty.current_milestone,
+        reason: reason.clone(),
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: bounty.rejected_at,
+    });
+
     msg!(
         "Bounty rejected. Reason: {}. Rep now: {}",
         reason,
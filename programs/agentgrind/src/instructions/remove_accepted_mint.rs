@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::state::*;
+
+/// Admin-only: stop accepting a mint for new bounties. Live bounties in that
+/// mint are unaffected and pay out normally.
+#[derive(Accounts)]
+pub struct RemoveAcceptedMint<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"mint", accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentGrindError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveAcceptedMint>) -> Result<()> {
+    msg!("Mint removed: {}", ctx.accounts.accepted_mint.mint);
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::AgentGrindError;
+use crate::events::DisputeResolved;
+use crate::state::*;
+use crate::utils::{close_vault, pay_lamports, required, vault_transfer};
+
+/// Arbiter rules on a Disputed bounty: pay the agent, refund the creator,
+/// or split the disputed payout (the current milestone). Whatever remains in
+/// escrow goes back to the creator, and the vault is closed (rent to creator).
+/// Native-SOL bounties omit the token accounts and pass the `claimer` wallet.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Disputed @ AgentGrindError::BountyNotDisputed
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", bounty.key().as_ref()],
+        bump
+    )]
+    pub vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Writable: closing the vault harvests withheld transfer fees into the mint
+    #[account(mut, address = bounty.mint @ AgentGrindError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", bounty.creator.as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"agent", agent_profile.wallet.as_ref()],
+        bump = agent_profile.bump,
+        constraint = bounty.claimer == Some(agent_profile.wallet) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        constraint = claimer_token_account.mint == bounty.mint,
+        constraint = claimer_token_account.owner == bounty.claimer.unwrap()
+    )]
+    pub claimer_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == bounty.mint,
+        constraint = creator_token_account.owner == bounty.creator
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: claimer wallet — receives lamports on SOL bounties
+    #[account(
+        mut,
+        constraint = bounty.claimer == Some(claimer.key()) @ AgentGrindError::UnauthorizedClaimer
+    )]
+    pub claimer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: rent destination for the closed vault (and SOL refund); must be the bounty creator
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ AgentGrindError::UnauthorizedCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = arbiter.key() == config.arbiter @ AgentGrindError::UnauthorizedArbiter
+    )]
+    pub arbiter: Signer<'info>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, ruling: DisputeRuling) -> Result<()> {
+    if let DisputeRuling::Split { agent_bps } = ruling {
+        require!(
+            agent_bps > 0 && agent_bps < BPS_DENOMINATOR,
+            AgentGrindError::InvalidSplit
+        );
+    }
+
+    let bounty = &mut ctx.accounts.bounty;
+    let disputed = bounty.payout_amount();
+    let agent_amount = ruling.agent_share(disputed);
+    let creator_amount = bounty.remaining_amount() - agent_amount;
+
+    // ── PDA signer seeds ──
+    let id_seed = bounty.id_seed();
+    let seeds = &[
+        b"bounty".as_ref(),
+        bounty.creator.as_ref(),
+        id_seed.as_slice(),
+        &[bounty.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if bounty.is_native() {
+        // ── Escrow → claimer / creator (lamports out of the bounty PDA) ──
+        let bounty_info = bounty.to_account_info();
+        if agent_amount > 0 {
+            pay_lamports(
+                &bounty_info,
+                &required(&ctx.accounts.claimer)?.to_account_info(),
+                agent_amount,
+            )?;
+        }
+        if creator_amount > 0 {
+            pay_lamports(&bounty_info, &ctx.accounts.creator.to_account_info(), creator_amount)?;
+        }
+    } else {
+        let token_program = required(&ctx.accounts.token_program)?;
+        let vault = required(&ctx.accounts.vault)?;
+        let mint = required(&ctx.accounts.mint)?;
+
+        // ── Vault → claimer ──
+        if agent_amount > 0 {
+            vault_transfer(
+                token_program,
+                vault,
+                mint,
+                required(&ctx.accounts.claimer_token_account)?,
+                bounty.to_account_info(),
+                agent_amount,
+                signer_seeds,
+            )?;
+        }
+
+        // ── Vault → creator ──
+        if creator_amount > 0 {
+            vault_transfer(
+                token_program,
+                vault,
+                mint,
+                required(&ctx.accounts.creator_token_account)?,
+                bounty.to_account_info(),
+                creator_amount,
+                signer_seeds,
+            )?;
+        }
+
+        // ── Close vault (rent back to creator) ──
+        close_vault(
+            token_program,
+            vault,
+            mint,
+            ctx.accounts.creator.to_account_info(),
+            bounty.to_account_info(),
+            signer_seeds,
+        )?;
+    }
+
+    bounty.released_amount = bounty.amount;
+    let old_status = bounty.status.clone();
+
+    // ── Outcome + reputation ──
+    let config = &ctx.accounts.config;
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let now = Clock::get()?.unix_timestamp;
+    creator_profile.recover_rep(now, config);
+    creator_profile.record_dispute(ruling, disputed, bounty.currency, bounty.key(), now, config);
+    let agent_profile = &mut ctx.accounts.agent_profile;
+    agent_profile.record_dispute(ruling, agent_amount, bounty.key(), now, config);
+    bounty.status = ruling.outcome();
+
+    emit!(DisputeResolved {
+        bounty: bounty.key(),
+        claimer: agent_profile.wallet,
+        ruling,
+        agent_amount,
+        creator_amount,
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: now,
+    });
+
+    msg!(
+        "Dispute resolved: {} to agent, {} to creator. Creator rep: {}, agent rep: {}",
+        agent_amount,
+        creator_amount,
+        creator_profile.reputation,
+        agent_profile.reputation
+    );
+
+    Ok(())
+}
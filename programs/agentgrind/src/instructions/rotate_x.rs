@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::XRotated;
+use crate::state::*;
+
+/// Profile owner switches to a different X handle (e.g. after renaming on X),
+/// keeping verification and reputation. Co-signed by config.x_verifier after
+/// OAuth on the new handle, like `link_x`. The old XHandle entry is closed
+/// (rent to the owner) and the new one registered.
+/// Subject to config.x_change_cooldown_secs.
+#[derive(Accounts)]
+#[instruction(new_handle: String)]
+pub struct RotateX<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", authority.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.x_verified @ AgentGrindError::XNotLinked
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"x_handle", &x_handle_seed(&profile.x_handle)[..]],
+        bump = old_x_handle.bump,
+        constraint = old_x_handle.profile == profile.key() @ AgentGrindError::XHandleMismatch
+    )]
+    pub old_x_handle: Account<'info, XHandle>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = XHandle::MAX_SIZE,
+        seeds = [b"x_handle", &x_handle_seed(&new_handle)[..]],
+        bump
+    )]
+    pub new_x_handle: Account<'info, XHandle>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Trusted verifier (the API) attesting that `authority` owns `new_handle`
+    #[account(
+        constraint = verifier.key() == config.x_verifier @ AgentGrindError::UnauthorizedVerifier
+    )]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RotateX>, new_handle: String) -> Result<()> {
+    require!(
+        new_handle.len() <= MAX_X_HANDLE_LEN,
+        AgentGrindError::XHandleTooLong
+    );
+    require!(
+        !normalize_x_handle(&new_handle).is_empty(),
+        AgentGrindError::XHandleEmpty
+    );
+
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let profile = &mut ctx.accounts.profile;
+    require!(
+        normalize_x_handle(&new_handle) != normalize_x_handle(&profile.x_handle),
+        AgentGrindError::SameXHandle
+    );
+    require!(profile.can_change_x(now, config), AgentGrindError::XChangeCooldown);
+
+    // ── One profile per handle ──
+    let registry = &mut ctx.accounts.new_x_handle;
+    require!(
+        registry.profile == Pubkey::default(),
+        AgentGrindError::XHandleTaken
+    );
+    registry.handle = normalize_x_handle(&new_handle);
+    registry.profile = profile.key();
+    registry.wallet = profile.wallet;
+    registry.bump = ctx.bumps.new_x_handle;
+
+    profile.recover_rep(now, config);
+    let old_handle = std::mem::replace(&mut profile.x_handle, new_handle.clone());
+    profile.x_changed_at = now;
+
+    emit!(XRotated {
+        profile: profile.key(),
+        wallet: profile.wallet,
+        old_handle: old_handle.clone(),
+        new_handle: new_handle.clone(),
+        reputation: profile.reputation,
+        timestamp: now,
+    });
+
+    msg!("X handle rotated: @{} -> @{}", old_handle, new_handle);
+    Ok(())
+}
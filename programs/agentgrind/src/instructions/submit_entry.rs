@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::ContestEntrySubmitted;
+use crate::state::*;
+
+/// Agent enters a contest bounty before the deadline. One entry per agent;
+/// no claim lock and no entry cap, so any number of agents can compete.
+#[derive(Accounts)]
+pub struct SubmitEntry<'info> {
+    #[account(
+        mut,
+        constraint = bounty.kind == BountyKind::Contest @ AgentGrindError::WrongBountyKind,
+        constraint = bounty.status == BountyStatus::Open @ AgentGrindError::BountyNotOpen,
+        constraint = bounty.deadline > Clock::get()?.unix_timestamp @ AgentGrindError::DeadlineExpired
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = Submission::MAX_SIZE,
+        seeds = [b"submission", bounty.key().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(
+        init_if_needed,
+        payer = agent,
+        space = AgentProfile::MAX_SIZE,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitEntry>, proof_uri: String) -> Result<()> {
+    require!(
+        proof_uri.len() <= MAX_PROOF_URI_LEN,
+        AgentGrindError::ProofUriTooLong
+    );
+
+    let agent_profile = &mut ctx.accounts.agent_profile;
+    if agent_profile.wallet == Pubkey::default() {
+        agent_profile.version = ACCOUNT_VERSION;
+        agent_profile.wallet = ctx.accounts.agent.key();
+        agent_profile.reputation = ctx.accounts.config.rep_initial;
+        agent_profile.bump = ctx.bumps.agent_profile;
+    }
+    agent_profile.total_submitted += 1;
+
+    let submission = &mut ctx.accounts.submission;
+    submission.bounty = ctx.accounts.bounty.key();
+    submission.agent = ctx.accounts.agent.key();
+    submission.proof_uri = proof_uri.clone();
+    submission.submitted_at = Clock::get()?.unix_timestamp;
+    submission.prize = 0;
+    submission.bump = ctx.bumps.submission;
+
+    let bounty = &mut ctx.accounts.bounty;
+    bounty.entry_count += 1;
+
+    emit!(ContestEntrySubmitted {
+        bounty: bounty.key(),
+        submission: submission.key(),
+        agent: submission.agent,
+        proof_uri: proof_uri.clone(),
+        entry_count: bounty.entry_count,
+        timestamp: submission.submitted_at,
+    });
+
+    msg!(
+        "Contest entry #{} by {}: {}",
+        bounty.entry_count,
+        submission.agent,
+        proof_uri
+    );
+
+    Ok(())
+}
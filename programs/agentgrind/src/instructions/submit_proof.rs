@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::AgentGrindError;
+use crate::events::ProofSubmitted;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -32,12 +33,27 @@ pub fn handler(ctx: Context<SubmitProof>, proof_uri: String) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
     let agent_profile = &mut ctx.accounts.agent_profile;
 
+    let old_status = bounty.status.clone();
     bounty.proof_uri = proof_uri.clone();
     bounty.proof_submitted_at = Clock::get()?.unix_timestamp;
     bounty.status = BountyStatus::Submitted;
 
-    // Unlock agent so they can claim another bounty
-    agent_profile.active_bounty = None;
+    // Unlock agent so they can claim another bounty (milestone bounties keep
+    // the agent locked until the last milestone is submitted)
+    if bounty.is_final_milestone() {
+        agent_profile.active_bounty = None;
+    }
+    agent_profile.total_submitted += 1;
+
+    emit!(ProofSubmitted {
+        bounty: bounty.key(),
+        claimer: ctx.accounts.claimer.key(),
+        milestone: bounty.current_milestone,
+        proof_uri: proof_uri.clone(),
+        old_status,
+        new_status: bounty.status.clone(),
+        timestamp: bounty.proof_submitted_at,
+    });
 
     msg!("Proof submitted: {} at {}", proof_uri, bounty.proof_submitted_at);
 
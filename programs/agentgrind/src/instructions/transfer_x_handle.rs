@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::XHandleTransferred;
+use crate::state::*;
+
+/// Moves a registered X handle to the profile of a new wallet (wallet rotation).
+/// The verifier re-attests handle ownership off-chain and co-signs, like `link_x`.
+/// The old profile drops to the unverified tier and forfeits reputation above
+/// config.unverified_rep_cap; the new profile keeps its own reputation.
+/// Counts as an X change for both profiles, so the cooldown applies to each.
+#[derive(Accounts)]
+pub struct TransferXHandle<'info> {
+    #[account(
+        mut,
+        seeds = [b"x_handle", &x_handle_seed(&x_handle_account.handle)[..]],
+        bump = x_handle_account.bump,
+        constraint = x_handle_account.profile == old_profile.key() @ AgentGrindError::XHandleMismatch
+    )]
+    pub x_handle_account: Account<'info, XHandle>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", old_profile.wallet.as_ref()],
+        bump = old_profile.bump
+    )]
+    pub old_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", new_wallet.key().as_ref()],
+        bump = new_profile.bump,
+        constraint = !new_profile.x_verified @ AgentGrindError::XAlreadyVerified,
+        constraint = new_profile.transferred_to.is_none() @ AgentGrindError::ProfileTransferred
+    )]
+    pub new_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub new_wallet: Signer<'info>,
+
+    /// Trusted verifier (the API) attesting that `new_wallet` controls the handle
+    #[account(
+        constraint = verifier.key() == config.x_verifier @ AgentGrindError::UnauthorizedVerifier
+    )]
+    pub verifier: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<TransferXHandle>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.old_profile.can_change_x(now, config)
+            && ctx.accounts.new_profile.can_change_x(now, config),
+        AgentGrindError::XChangeCooldown
+    );
+
+    // ── Old profile loses the handle ──
+    let old_profile = &mut ctx.accounts.old_profile;
+    old_profile.recover_rep(now, config);
+    let x_handle = old_profile.x_handle.clone();
+    old_profile.x_handle = String::new();
+    old_profile.set_x_verified(false, now, config);
+    old_profile.x_changed_at = now;
+
+    // ── New profile gains it ──
+    let new_profile = &mut ctx.accounts.new_profile;
+    new_profile.recover_rep(now, config);
+    new_profile.x_handle = x_handle.clone();
+    new_profile.set_x_verified(true, now, config);
+    new_profile.x_changed_at = now;
+
+    let registry = &mut ctx.accounts.x_handle_account;
+    registry.profile = new_profile.key();
+    registry.wallet = new_profile.wallet;
+
+    emit!(XHandleTransferred {
+        x_handle: registry.handle.clone(),
+        old_profile: old_profile.key(),
+        new_profile: new_profile.key(),
+        new_wallet: new_profile.wallet,
+        timestamp: now,
+    });
+
+    msg!("X handle @{} moved to {}", x_handle, new_profile.wallet);
+    Ok(())
+}
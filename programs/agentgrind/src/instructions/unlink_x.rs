@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::events::XUnlinked;
+use crate::state::*;
+
+/// Profile owner removes their X handle (e.g. after a compromise).
+/// The profile drops back to the unverified tier: unverified bounty caps (never
+/// above its reputation tier's, so probation still applies), and reputation above
+/// config.unverified_rep_cap is forfeited. The XHandle entry is
+/// closed (rent to the owner) so the handle can be linked again.
+/// Subject to config.x_change_cooldown_secs.
+#[derive(Accounts)]
+pub struct UnlinkX<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", authority.key().as_ref()],
+        bump = profile.bump,
+        constraint = profile.x_verified @ AgentGrindError::XNotLinked
+    )]
+    pub profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"x_handle", &x_handle_seed(&profile.x_handle)[..]],
+        bump = x_handle_account.bump,
+        constraint = x_handle_account.profile == profile.key() @ AgentGrindError::XHandleMismatch
+    )]
+    pub x_handle_account: Account<'info, XHandle>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnlinkX>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    let profile = &mut ctx.accounts.profile;
+    require!(profile.can_change_x(now, config), AgentGrindError::XChangeCooldown);
+
+    profile.recover_rep(now, config);
+    let old_reputation = profile.reputation;
+    let x_handle = std::mem::take(&mut profile.x_handle);
+    profile.set_x_verified(false, now, config);
+    profile.x_changed_at = now;
+
+    emit!(XUnlinked {
+        profile: profile.key(),
+        wallet: profile.wallet,
+        x_handle: x_handle.clone(),
+        old_reputation,
+        new_reputation: profile.reputation,
+        timestamp: now,
+    });
+
+    msg!("X handle unlinked: @{}. Rep now: {}", x_handle, profile.reputation);
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::AgentGrindError;
+use crate::state::*;
+
+/// Partial update of the ProgramConfig. `None` leaves a field unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigParams {
+    pub new_admin: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub fee_bps: Option<u16>,
+    pub rep_initial: Option<i64>,
+    pub rep_cap: Option<i64>,
+    pub unverified_rep_cap: Option<i64>,
+    pub rep_complete: Option<i64>,
+    pub rep_reject: Option<i64>,
+    pub rep_ghost: Option<i64>,
+    pub agent_rep_approved: Option<i64>,
+    pub agent_rep_rejected: Option<i64>,
+    pub agent_rep_abandoned: Option<i64>,
+    pub agent_rep_timed_out: Option<i64>,
+    pub rep_dispute_lost: Option<i64>,
+    pub agent_rep_dispute_lost: Option<i64>,
+    pub rep_tier_blocked: Option<i64>,
+    pub rep_tier_limited: Option<i64>,
+    pub rep_baseline: Option<i64>,
+    pub rep_recovery_per_day: Option<i64>,
+    pub unverified_max_amount: Option<u64>,
+    pub limited_max_amount: Option<u64>,
+    pub unverified_max_lamports: Option<u64>,
+    pub limited_max_lamports: Option<u64>,
+    pub probation_max_amount: Option<u64>,
+    pub probation_max_lamports: Option<u64>,
+    pub rep_reference_amount: Option<u64>,
+    pub rep_reference_lamports: Option<u64>,
+    pub rep_weight_min_bps: Option<u16>,
+    pub rep_weight_max_bps: Option<u16>,
+    pub review_window_secs: Option<i64>,
+    pub min_review_window_secs: Option<i64>,
+    pub max_review_window_secs: Option<i64>,
+    pub ghost_penalty_floor_bps: Option<u16>,
+    pub claim_window_secs: Option<i64>,
+    pub dispute_window_secs: Option<i64>,
+    pub x_change_cooldown_secs: Option<i64>,
+    pub arbiter: Option<Pubkey>,
+    pub x_verifier: Option<Pubkey>,
+}
+
+/// Admin-only: tune platform parameters without redeploying.
+/// Changes apply to every instruction from the next transaction on,
+/// including bounties that are already live (e.g. the claim and dispute windows);
+/// a bounty's review window is fixed when it is created.
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ AgentGrindError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(v) = params.new_admin { config.admin = v; }
+    if let Some(v) = params.treasury { config.treasury = v; }
+    if let Some(v) = params.fee_bps { config.fee_bps = v; }
+    if let Some(v) = params.rep_initial { config.rep_initial = v; }
+    if let Some(v) = params.rep_cap { config.rep_cap = v; }
+    if let Some(v) = params.unverified_rep_cap { config.unverified_rep_cap = v; }
+    if let Some(v) = params.rep_complete { config.rep_complete = v; }
+    if let Some(v) = params.rep_reject { config.rep_reject = v; }
+    if let Some(v) = params.rep_ghost { config.rep_ghost = v; }
+    if let Some(v) = params.agent_rep_approved { config.agent_rep_approved = v; }
+    if let Some(v) = params.agent_rep_rejected { config.agent_rep_rejected = v; }
+    if let Some(v) = params.agent_rep_abandoned { config.agent_rep_abandoned = v; }
+    if let Some(v) = params.agent_rep_timed_out { config.agent_rep_timed_out = v; }
+    if let Some(v) = params.rep_dispute_lost { config.rep_dispute_lost = v; }
+    if let Some(v) = params.agent_rep_dispute_lost { config.agent_rep_dispute_lost = v; }
+    if let Some(v) = params.rep_tier_blocked { config.rep_tier_blocked = v; }
+    if let Some(v) = params.rep_tier_limited { config.rep_tier_limited = v; }
+    if let Some(v) = params.rep_baseline { config.rep_baseline = v; }
+    if let Some(v) = params.rep_recovery_per_day { config.rep_recovery_per_day = v; }
+    if let Some(v) = params.unverified_max_amount { config.unverified_max_amount = v; }
+    if let Some(v) = params.limited_max_amount { config.limited_max_amount = v; }
+    if let Some(v) = params.unverified_max_lamports { config.unverified_max_lamports = v; }
+    if let Some(v) = params.limited_max_lamports { config.limited_max_lamports = v; }
+    if let Some(v) = params.probation_max_amount { config.probation_max_amount = v; }
+    if let Some(v) = params.probation_max_lamports { config.probation_max_lamports = v; }
+    if let Some(v) = params.rep_reference_amount { config.rep_reference_amount = v; }
+    if let Some(v) = params.rep_reference_lamports { config.rep_reference_lamports = v; }
+    if let Some(v) = params.rep_weight_min_bps { config.rep_weight_min_bps = v; }
+    if let Some(v) = params.rep_weight_max_bps { config.rep_weight_max_bps = v; }
+    if let Some(v) = params.review_window_secs { config.review_window_secs = v; }
+    if let Some(v) = params.min_review_window_secs { config.min_review_window_secs = v; }
+    if let Some(v) = params.max_review_window_secs { config.max_review_window_secs = v; }
+    if let Some(v) = params.ghost_penalty_floor_bps { config.ghost_penalty_floor_bps = v; }
+    if let Some(v) = params.claim_window_secs { config.claim_window_secs = v; }
+    if let Some(v) = params.dispute_window_secs { config.dispute_window_secs = v; }
+    if let Some(v) = params.x_change_cooldown_secs { config.x_change_cooldown_secs = v; }
+    if let Some(v) = params.arbiter { config.arbiter = v; }
+    if let Some(v) = params.x_verifier { config.x_verifier = v; }
+
+    config.validate()?;
+
+    msg!("Config updated by {}", ctx.accounts.admin.key());
+    Ok(())
+}
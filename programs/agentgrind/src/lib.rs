@@ -3,8 +3,11 @@ use anchor_lang::prelude::*;
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod utils;
+pub mod events;
 
 use instructions::*;
+use state::{BountyKind, DisputeRuling};
 
 declare_id!("HMUV19dpEUPxjSYdqnp4usgcsjHp6WrZ5ijutmKXcTDz");
 
@@ -12,14 +15,33 @@ declare_id!("HMUV19dpEUPxjSYdqnp4usgcsjHp6WrZ5ijutmKXcTDz");
 pub mod agentgrind {
     use super::*;
 
-    /// Create a new bounty (deposits USDC into escrow, inits CreatorProfile if needed)
+    /// Create a new bounty (fee to treasury, rest into escrow, inits CreatorProfile if needed).
+    /// The address comes from the creator's next_bounty_index; `bounty_id` is an optional label.
+    /// `milestones` optionally splits `amount` into staged payouts (empty = single payout).
+    /// `kind` picks the single-claimer flow or a contest.
+    /// `description_hash` commits to the off-chain description (SHA-256).
+    /// `review_window_secs` must be within the config bounds (None = config default).
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bounty(
         ctx: Context<CreateBounty>,
         bounty_id: String,
         amount: u64,
         deadline: i64,
+        milestones: Vec<u64>,
+        kind: BountyKind,
+        description_hash: [u8; 32],
+        review_window_secs: Option<i64>,
     ) -> Result<()> {
-        instructions::create_bounty::handler(ctx, bounty_id, amount, deadline)
+        instructions::create_bounty::handler(
+            ctx,
+            bounty_id,
+            amount,
+            deadline,
+            milestones,
+            kind,
+            description_hash,
+            review_window_secs,
+        )
     }
 
     /// Initialize a CreatorProfile without creating a bounty.
@@ -27,12 +49,27 @@ pub mod agentgrind {
         instructions::init_profile::handler(ctx)
     }
 
+    /// Creator or sponsor tops up an Open/Claimed bounty (fee applies, cap re-checked)
+    pub fn increase_bounty(ctx: Context<IncreaseBounty>, amount: u64) -> Result<()> {
+        instructions::increase_bounty::handler(ctx, amount)
+    }
+
+    /// Creator pushes out the deadline of an unclaimed bounty
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, new_deadline: i64) -> Result<()> {
+        instructions::extend_deadline::handler(ctx, new_deadline)
+    }
+
+    /// Creator amends a Claimed bounty (deadline, lower amount, description) with the claimer co-signing
+    pub fn amend_bounty(ctx: Context<AmendBounty>, params: AmendBountyParams) -> Result<()> {
+        instructions::amend_bounty::handler(ctx, params)
+    }
+
     /// Agent claims an open bounty
     pub fn claim_bounty(ctx: Context<ClaimBounty>) -> Result<()> {
         instructions::claim_bounty::handler(ctx)
     }
 
-    /// Agent submits proof of work
+    /// Agent submits proof of work (for the current milestone)
     pub fn submit_proof(ctx: Context<SubmitProof>, proof_uri: String) -> Result<()> {
         instructions::submit_proof::handler(ctx, proof_uri)
     }
@@ -41,28 +78,138 @@ pub mod agentgrind {
         instructions::abandon_claim::handler(ctx)
     }
 
-    /// Creator approves submitted proof → pays agent (+15 rep)
+    /// Anyone can release a claim whose work window lapsed without proof (reopens bounty)
+    pub fn expire_claim(ctx: Context<ExpireClaim>) -> Result<()> {
+        instructions::expire_claim::handler(ctx)
+    }
+
+    /// Creator approves submitted proof → pays agent (+config.rep_complete)
     pub fn approve_and_pay(ctx: Context<ApproveAndPay>) -> Result<()> {
         instructions::approve_and_pay::handler(ctx)
     }
 
-    /// Creator rejects submitted proof → reopens bounty (-15 rep)
+    /// Creator rejects submitted proof → disputable for config.dispute_window_secs,
+    /// then reopens (config.rep_reject)
     pub fn reject_bounty(ctx: Context<RejectBounty>, reason: String) -> Result<()> {
         instructions::reject_bounty::handler(ctx, reason)
     }
 
-    /// Anyone can call after 48h review window → auto-pays agent (-30 rep to creator)
+    /// Claimer disputes a rejection within the dispute window (locks the vault)
+    pub fn open_dispute(ctx: Context<OpenDispute>) -> Result<()> {
+        instructions::open_dispute::handler(ctx)
+    }
+
+    /// Arbiter rules on a dispute: pay agent, refund creator, or split
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, ruling: DisputeRuling) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, ruling)
+    }
+
+    /// Anyone can call after the review window → auto-pays agent (config.rep_ghost to creator)
     pub fn finalize_bounty(ctx: Context<FinalizeBounty>) -> Result<()> {
         instructions::finalize_bounty::handler(ctx)
     }
 
+    /// Agent enters a contest bounty before the deadline
+    pub fn submit_entry(ctx: Context<SubmitEntry>, proof_uri: String) -> Result<()> {
+        instructions::submit_entry::handler(ctx, proof_uri)
+    }
+
+    /// Creator picks contest winners with a prize split (bps, sums to 10000)
+    pub fn judge_contest<'info>(
+        ctx: Context<'_, '_, 'info, 'info, JudgeContest<'info>>,
+        prize_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::judge_contest::handler(ctx, prize_bps)
+    }
+
+    /// Anyone can call after the review window past an unjudged contest's deadline
+    /// → even split across entries (config.rep_ghost to creator)
+    pub fn finalize_contest<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeContest<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_contest::handler(ctx)
+    }
+
     /// Creator cancels unclaimed bounty after deadline (refund, 0 rep change)
     pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
         instructions::cancel_bounty::handler(ctx)
     }
 
-    /// Link verified X handle to CreatorProfile (called by API after OAuth verification)
+    /// Creator closes a Completed/Cancelled bounty (rent back, emits BountyClosed)
+    pub fn close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+        instructions::close_bounty::handler(ctx)
+    }
+
+    /// Link verified X handle to CreatorProfile (co-signed by config.x_verifier after OAuth).
+    /// Fails if another profile already owns the handle.
     pub fn link_x(ctx: Context<LinkX>, x_handle: String) -> Result<()> {
         instructions::link_x::handler(ctx, x_handle)
     }
+
+    /// Move an X handle to a new wallet's profile (co-signed by config.x_verifier)
+    pub fn transfer_x_handle(ctx: Context<TransferXHandle>) -> Result<()> {
+        instructions::transfer_x_handle::handler(ctx)
+    }
+
+    /// Remove the linked X handle (back to unverified limits; cooldown applies)
+    pub fn unlink_x(ctx: Context<UnlinkX>) -> Result<()> {
+        instructions::unlink_x::handler(ctx)
+    }
+
+    /// Switch to a new X handle, keeping reputation (co-signed by config.x_verifier; cooldown applies)
+    pub fn rotate_x(ctx: Context<RotateX>, new_handle: String) -> Result<()> {
+        instructions::rotate_x::handler(ctx, new_handle)
+    }
+
+    /// Offer the creator profile to a new wallet (None withdraws the offer)
+    pub fn propose_profile_transfer(
+        ctx: Context<ProposeProfileTransfer>,
+        new_wallet: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_profile_transfer::handler(ctx, new_wallet)
+    }
+
+    /// New wallet takes over reputation, history and X link; the old profile is tombstoned
+    pub fn accept_profile_transfer(ctx: Context<AcceptProfileTransfer>) -> Result<()> {
+        instructions::accept_profile_transfer::handler(ctx)
+    }
+
+    /// Anyone: upgrade a v1 Bounty to the current layout (payer tops up rent)
+    pub fn migrate_bounty(ctx: Context<MigrateBounty>) -> Result<()> {
+        instructions::migrate_bounty::handler(ctx)
+    }
+
+    /// Anyone: upgrade a v1 CreatorProfile (registers its X handle if verified)
+    pub fn migrate_creator_profile(ctx: Context<MigrateCreatorProfile>) -> Result<()> {
+        instructions::migrate_creator_profile::handler(ctx)
+    }
+
+    /// Anyone: upgrade a v1 AgentProfile to the current layout
+    pub fn migrate_agent_profile(ctx: Context<MigrateAgentProfile>) -> Result<()> {
+        instructions::migrate_agent_profile::handler(ctx)
+    }
+
+    /// Admin: one-time setup of the ProgramConfig (treasury + platform fee, defaults for the rest)
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        treasury: Pubkey,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::initialize_config::handler(ctx, treasury, fee_bps)
+    }
+
+    /// Admin: update fee, treasury, reputation economy and limits
+    pub fn update_config(ctx: Context<UpdateConfig>, params: UpdateConfigParams) -> Result<()> {
+        instructions::update_config::handler(ctx, params)
+    }
+
+    /// Admin: allowlist a mint (SPL Token or Token-2022) for new bounties
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>) -> Result<()> {
+        instructions::add_accepted_mint::handler(ctx)
+    }
+
+    /// Admin: remove a mint from the allowlist (live bounties unaffected)
+    pub fn remove_accepted_mint(ctx: Context<RemoveAcceptedMint>) -> Result<()> {
+        instructions::remove_accepted_mint::handler(ctx)
+    }
 }
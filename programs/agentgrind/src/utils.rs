@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::{
+    self, get_mint_extension_data, CloseAccount, HarvestWithheldTokensToMint, Mint, TokenAccount,
+    TokenInterface, TransferChecked,
+};
+
+use crate::errors::AgentGrindError;
+
+/// Token-2022 transfer-fee config of `mint`, if it has one (never for legacy SPL mints)
+pub fn transfer_fee_config(mint: &InterfaceAccount<Mint>) -> Option<TransferFeeConfig> {
+    get_mint_extension_data::<TransferFeeConfig>(&mint.to_account_info()).ok()
+}
+
+/// Gross amount to send so that exactly `net` arrives after the mint's transfer fee
+pub fn gross_up_for_transfer_fee(mint: &InterfaceAccount<Mint>, net: u64) -> Result<u64> {
+    match transfer_fee_config(mint) {
+        Some(config) => {
+            let fee = config
+                .calculate_inverse_epoch_fee(Clock::get()?.epoch, net)
+                .ok_or(AgentGrindError::InvalidAmount)?;
+            Ok(net + fee)
+        }
+        None => Ok(net),
+    }
+}
+
+/// Close an emptied vault, returning rent to `destination`. Fees withheld on the
+/// vault by a transfer-fee mint are harvested to the mint first, since Token-2022
+/// won't close an account holding them (settlement contexts take the mint `mut`).
+pub fn close_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if transfer_fee_config(mint).is_some() {
+        let harvest_accounts = HarvestWithheldTokensToMint {
+            token_program_id: token_program.to_account_info(),
+            mint: mint.to_account_info(),
+        };
+        let harvest_ctx = CpiContext::new(token_program.to_account_info(), harvest_accounts);
+        token_interface::harvest_withheld_tokens_to_mint(harvest_ctx, vec![vault.to_account_info()])?;
+    }
+
+    let close_accounts = CloseAccount {
+        account: vault.to_account_info(),
+        destination,
+        authority,
+    };
+    let close_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), close_accounts, signer_seeds);
+    token_interface::close_account(close_ctx)
+}
+
+/// Unwrap an optional account that the bounty's currency requires
+pub fn required<T>(account: &Option<T>) -> Result<&T> {
+    account
+        .as_ref()
+        .ok_or_else(|| error!(AgentGrindError::MissingEscrowAccount))
+}
+
+/// Transfer `amount` out of the vault, signed by the bounty PDA
+pub fn vault_transfer<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to: to.to_account_info(),
+        authority,
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds);
+    token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+}
+
+/// Pay lamports out of a native-SOL bounty. The Bounty PDA is program-owned, so it
+/// is debited directly; it must stay rent-exempt afterwards.
+pub fn pay_lamports(bounty: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let rent_floor = Rent::get()?.minimum_balance(bounty.data_len());
+    require!(
+        bounty
+            .lamports()
+            .checked_sub(amount)
+            .is_some_and(|left| left >= rent_floor),
+        AgentGrindError::InsufficientEscrow
+    );
+    bounty.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+    Ok(())
+}
+
+/// Resize a program-owned account to `space` (payer tops up rent) and overwrite it
+/// with `value` in the current layout. Used by the `migrate_*` instructions.
+pub fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    value: &T,
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        anchor_lang::system_program::transfer(
+            CpiContext::new(system_program.clone(), cpi_accounts),
+            shortfall,
+        )?;
+    }
+    account.resize(space)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    value.try_serialize(&mut &mut data[..])
+}